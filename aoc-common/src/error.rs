@@ -0,0 +1,135 @@
+use std::{error::Error, fmt};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Position of the offending text within the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of the first offending character.
+    pub column: usize,
+    /// Number of offending characters, at least 1.
+    pub width: usize,
+    /// The whole offending line.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            location: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(location: Location, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            location: Some(location),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Renders the error with the offending line and a caret under the
+    /// offending text.
+    pub fn render(&self) -> String {
+        let mut out = self.message.clone();
+        let Some(location) = &self.location else {
+            if let Some(day) = self.day {
+                out.push_str(&format!("\n --> day {day:02}"));
+            }
+            return out;
+        };
+
+        let number = location.line.to_string();
+        let pad = " ".repeat(number.len());
+        let day = match self.day {
+            Some(day) => format!("day {day:02}, "),
+            None => String::new(),
+        };
+        out.push_str(&format!(
+            "\n{pad}--> {day}line {}, column {}",
+            location.line, location.column
+        ));
+        out.push_str(&format!("\n{pad} |"));
+        out.push_str(&format!("\n{number} | {}", location.text));
+        out.push_str(&format!(
+            "\n{pad} | {}{}",
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width)
+        ));
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day:02}: ")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error() -> ParseError {
+        ParseError::at(
+            Location {
+                line: 12,
+                column: 7,
+                width: 3,
+                text: "32T3K abc".to_string(),
+            },
+            "could not parse bid",
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = "day 07: line 12, column 7: could not parse bid";
+        let result = error().with_day(7).to_string();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_render() {
+        let expected = "could not parse bid
+  --> day 07, line 12, column 7
+   |
+12 | 32T3K abc
+   |       ^^^";
+        let result = error().with_day(7).render();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_render_without_location() {
+        let expected = "input is empty\n --> day 06";
+        let result = ParseError::new("input is empty").with_day(6).render();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,297 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Position on a grid, `x` grows to the right and `y` grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Displacement between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Point) -> u64 {
+        (other - self).chebyshev()
+    }
+
+    pub fn euclidean(self, other: Point) -> f64 {
+        (other - self).euclidean()
+    }
+
+    /// The four orthogonal neighbours, clockwise from north.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.vector())
+    }
+
+    /// The eight surrounding points, clockwise from north.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d.vector())
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> u64 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    pub fn euclidean(self) -> f64 {
+        (self.x as f64).hypot(self.y as f64)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, k: i64) -> Vector {
+        Vector::new(self.x * k, self.y * k)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Compass directions including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::North => Vector::new(0, -1),
+            Direction8::NorthEast => Vector::new(1, -1),
+            Direction8::East => Vector::new(1, 0),
+            Direction8::SouthEast => Vector::new(1, 1),
+            Direction8::South => Vector::new(0, 1),
+            Direction8::SouthWest => Vector::new(-1, 1),
+            Direction8::West => Vector::new(-1, 0),
+            Direction8::NorthWest => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manhattan() {
+        let cases = vec![
+            (Point::new(1, 6), Point::new(5, 11), 9),
+            (Point::new(4, 0), Point::new(9, 10), 15),
+            (Point::new(0, 2), Point::new(12, 7), 17),
+            (Point::new(0, 11), Point::new(5, 11), 5),
+            (Point::new(-3, 2), Point::new(1, -1), 7),
+        ];
+        for (p0, p1, expected) in cases {
+            let result = p0.manhattan(p1);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        let cases = vec![
+            (Point::new(1, 6), Point::new(5, 11), 5),
+            (Point::new(-3, 2), Point::new(1, -1), 4),
+            (Point::new(2, 2), Point::new(2, 2), 0),
+        ];
+        for (p0, p1, expected) in cases {
+            let result = p0.chebyshev(p1);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_euclidean() {
+        let result = Point::new(-1, 2).euclidean(Point::new(2, -2));
+        assert_eq!(result, 5.0);
+    }
+
+    #[test]
+    fn test_ops() {
+        let p = Point::new(3, -4);
+        let v = Vector::new(-1, 2);
+        assert_eq!(p + v, Point::new(2, -2));
+        assert_eq!(p - v, Point::new(4, -6));
+        assert_eq!(Point::new(2, -2) - p, v);
+        assert_eq!(v * 3 + -v, Vector::new(-2, 4));
+        let mut q = p;
+        q += v;
+        q -= v * 2;
+        assert_eq!(q, Point::new(4, -6));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        for d in Direction::ALL {
+            assert_eq!(d.vector(), Direction8::from(d).vector());
+            assert_eq!(d.vector(), -d.opposite().vector());
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(0, 0);
+        let expected4 = vec![
+            Point::new(0, -1),
+            Point::new(1, 0),
+            Point::new(0, 1),
+            Point::new(-1, 0),
+        ];
+        assert_eq!(p.neighbours4().collect::<Vec<_>>(), expected4);
+        assert!(p.neighbours8().all(|n| p.chebyshev(n) == 1));
+        assert_eq!(p.neighbours8().count(), 8);
+    }
+}
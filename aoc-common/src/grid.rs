@@ -0,0 +1,227 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{error::Result, geometry::Point, parse::lines};
+
+/// Rectangular grid stored row by row, indexed by `Point { x: column, y: row }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses a character map, one row per line.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map converting each character with `cell`, whose
+    /// error message is reported at the offending character.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> std::result::Result<T, String>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines(input) {
+            let mut count = 0;
+            for (i, c) in line.text.char_indices() {
+                let span = &line.text[i..i + c.len_utf8()];
+                cells.push(cell(c).map_err(|msg| line.error(span, msg))?);
+                count += 1;
+            }
+            match width {
+                None => width = Some(count),
+                Some(width) if width != count => {
+                    let msg = format!("expected {width} columns, found {count}");
+                    return Err(line.error(line.text, msg));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid should be rectangular");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(|i| &mut self.cells[i])
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Orthogonal neighbours within the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours4().filter(|p| self.contains(*p))
+    }
+
+    /// Orthogonal and diagonal neighbours within the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours8().filter(|p| self.contains(*p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("Point should be within the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .expect("Point should be within the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = Grid::new(3, 2, "abcdef".chars().collect());
+        let result = grid();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let location = Grid::parse("abc\nde").unwrap_err().location.unwrap();
+        assert_eq!((location.line, location.column, location.width), (2, 1, 2));
+    }
+
+    #[test]
+    fn test_parse_with_error() {
+        let result = Grid::parse_with("..\n.x", |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err("expected `.` or `#`".to_string()),
+        });
+        let location = result.unwrap_err().location.unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        let rows = grid.rows().map(|r| r.iter().collect::<String>());
+        assert_eq!(rows.collect::<Vec<_>>(), vec!["abc", "def"]);
+        let columns = grid.columns().map(|c| c.collect::<String>());
+        assert_eq!(columns.collect::<Vec<_>>(), vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let result4 = grid.neighbours4(Point::ORIGIN).collect::<Vec<_>>();
+        assert_eq!(result4, vec![Point::new(1, 0), Point::new(0, 1)]);
+        let result8 = grid.neighbours8(Point::new(1, 1)).count();
+        assert_eq!(result8, 5);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let expected = Grid::new(2, 3, "adbecf".chars().collect());
+        let result = grid().transpose();
+        assert_eq!(result, expected);
+    }
+}
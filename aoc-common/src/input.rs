@@ -0,0 +1,159 @@
+use std::{
+    env,
+    error::Error,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at the directory holding the puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Input directory used when `AOC_INPUT_DIR` is not set.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+pub const YEAR: u16 = 2023;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// The day's file in the input directory, e.g. `inputs/2023/day07.txt`.
+    Day(u8),
+}
+
+impl InputSource {
+    /// Source given on the command line, where `-` stands for stdin, falling
+    /// back to the day's file in the input directory.
+    pub fn from_arg(arg: Option<&Path>, day: u8) -> Self {
+        match arg {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => InputSource::Day(day),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError::new(self, err))?;
+                Ok(input)
+            }
+            InputSource::File(path) => read_file(self, path),
+            InputSource::Day(day) => read_file(self, &day_path(&input_dir(), *day)),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Day(day) => write!(f, "{}", day_path(&input_dir(), *day).display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: String,
+    pub error: io::Error,
+}
+
+impl InputError {
+    fn new(source: &InputSource, error: io::Error) -> Self {
+        InputError {
+            source: source.to_string(),
+            error,
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.source, self.error)
+    }
+}
+
+impl Error for InputError {}
+
+/// Directory from `AOC_INPUT_DIR`, defaulting to `inputs`.
+pub fn input_dir() -> PathBuf {
+    input_dir_from(env::var_os(INPUT_DIR_VAR))
+}
+
+fn input_dir_from(var: Option<OsString>) -> PathBuf {
+    match var {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_INPUT_DIR),
+    }
+}
+
+/// Path of the day's input within `dir`, e.g. `dir/2023/day07.txt`.
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(YEAR.to_string()).join(format!("day{day:02}.txt"))
+}
+
+fn read_file(source: &InputSource, path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| InputError::new(source, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        let cases = vec![
+            (Some(Path::new("-")), InputSource::Stdin),
+            (
+                Some(Path::new("in.txt")),
+                InputSource::File(PathBuf::from("in.txt")),
+            ),
+            (None, InputSource::Day(7)),
+        ];
+        for (arg, expected) in cases {
+            let result = InputSource::from_arg(arg, 7);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_input_dir_from() {
+        let cases = vec![
+            (None, PathBuf::from("inputs")),
+            (Some(OsString::new()), PathBuf::from("inputs")),
+            (Some(OsString::from("/tmp/aoc")), PathBuf::from("/tmp/aoc")),
+        ];
+        for (var, expected) in cases {
+            let result = input_dir_from(var);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_day_path() {
+        let expected = PathBuf::from("inputs/2023/day07.txt");
+        let result = day_path(Path::new("inputs"), 7);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        fs::write(&path, "1abc2\n").unwrap();
+        let result = InputSource::File(path.clone()).read().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result, "1abc2\n");
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let result = source.read().unwrap_err().to_string();
+        assert!(result.starts_with("could not read does/not/exist.txt: "));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod node;
+pub mod parse;
+pub mod range;
+pub mod solution;
+
+pub use error::{Location, ParseError, Result};
+pub use grid::Grid;
+pub use solution::{Solution, Unsolved};
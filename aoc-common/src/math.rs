@@ -0,0 +1,44 @@
+pub fn lcm(first: u64, second: u64) -> u64 {
+    first * second / gcd(first, second)
+}
+
+pub fn gcd(first: u64, second: u64) -> u64 {
+    let mut max = first;
+    let mut min = second;
+    if min > max {
+        std::mem::swap(&mut max, &mut min);
+    }
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return min;
+        }
+
+        max = min;
+        min = res;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        let cases = vec![(12, 18, 6), (18, 12, 6), (7, 13, 1), (5, 5, 5)];
+        for (first, second, expected) in cases {
+            let result = gcd(first, second);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_lcm() {
+        let cases = vec![(4, 6, 12), (2, 3, 6), (5, 5, 5)];
+        for (first, second, expected) in cases {
+            let result = lcm(first, second);
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{error::Result, parse::Line};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    pub label: String,
+    pub left: String,
+    pub right: String,
+}
+
+pub fn parse_node(line: &Line) -> Result<Node> {
+    let (label, children) = line
+        .text
+        .split_once(" = ")
+        .ok_or_else(|| line.error(line.text, "expected `LABEL = (LEFT, RIGHT)`"))?;
+
+    let (left, right) = children
+        .strip_prefix('(')
+        .and_then(|c| c.strip_suffix(')'))
+        .and_then(|c| c.split_once(", "))
+        .ok_or_else(|| line.error(children, "expected `(LEFT, RIGHT)`"))?;
+
+    Ok(Node {
+        label: label.to_string(),
+        left: left.to_string(),
+        right: right.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_node() {
+        let input = Line {
+            number: 1,
+            text: "AAA = (BBB, CCC)",
+        };
+        let expected = Node {
+            label: "AAA".to_string(),
+            left: "BBB".to_string(),
+            right: "CCC".to_string(),
+        };
+        let result = parse_node(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_node_error() {
+        let input = Line {
+            number: 4,
+            text: "AAA = BBB, CCC",
+        };
+        let location = parse_node(&input).unwrap_err().location.unwrap();
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, 7);
+    }
+}
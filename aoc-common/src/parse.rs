@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use crate::error::{Location, ParseError, Result};
+
+/// A line of the puzzle input together with its 1-based line number, used to
+/// locate parse errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Splits the input into numbered lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at `span`, which should be a slice of this line.
+    pub fn error(&self, span: &str, message: impl Into<String>) -> ParseError {
+        let (column, width) = match self.offset(span) {
+            Some(offset) => (
+                self.text[..offset].chars().count() + 1,
+                span.chars().count().max(1),
+            ),
+            None => (1, self.text.chars().count().max(1)),
+        };
+        ParseError::at(
+            Location {
+                line: self.number,
+                column,
+                width,
+                text: self.text.to_string(),
+            },
+            message,
+        )
+    }
+
+    /// Empty slice at the end of the line, for reporting missing text.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// Parses `span` or reports it as the offending text.
+    pub fn parse<T: FromStr>(&self, span: &str, what: &str) -> Result<T> {
+        span.trim()
+            .parse()
+            .map_err(|_| self.error(span, format!("could not parse {what} `{}`", span.trim())))
+    }
+
+    /// Parses a whitespace separated list of numbers, e.g. `79 14 55 13`.
+    pub fn numbers<T: FromStr>(&self, span: &str) -> Result<Vec<T>> {
+        span.split_whitespace()
+            .map(|num| self.parse(num, "number"))
+            .collect()
+    }
+
+    /// Returns the part of a `Label: body` line after the colon.
+    pub fn strip_label(&self) -> Result<&'a str> {
+        match self.text.split_once(':') {
+            Some((_, body)) => Ok(body),
+            None => Err(self.error(self.text, "expected `Label: body`")),
+        }
+    }
+
+    fn offset(&self, span: &str) -> Option<usize> {
+        let start = self.text.as_ptr() as usize;
+        let pos = span.as_ptr() as usize;
+        (pos >= start && pos + span.len() <= start + self.text.len()).then(|| pos - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "a\nb";
+        let expected = vec![
+            Line {
+                number: 1,
+                text: "a",
+            },
+            Line {
+                number: 2,
+                text: "b",
+            },
+        ];
+        let result = lines(input).collect::<Vec<_>>();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let line = line(" 0 3  -6 9 ");
+        let expected = vec![0, 3, -6, 9];
+        let result = line.numbers::<i32>(line.text).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_numbers_error() {
+        let line = line("seeds: 79 1x 55");
+        let location = line
+            .numbers::<i64>(&line.text[6..])
+            .unwrap_err()
+            .location
+            .unwrap();
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 11);
+        assert_eq!(location.width, 2);
+        assert_eq!(location.text, "seeds: 79 1x 55");
+    }
+
+    #[test]
+    fn test_strip_label() {
+        let line = line("Time:      7  15   30");
+        let expected = "      7  15   30";
+        let result = line.strip_label().unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_strip_label_error() {
+        let line = line("Time 7 15 30");
+        let result = line.strip_label();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_end() {
+        let line = line("32T3K");
+        let location = line.error(line.end(), "missing bid").location.unwrap();
+        assert_eq!(location.column, 6);
+        assert_eq!(location.width, 1);
+    }
+}
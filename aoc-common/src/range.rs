@@ -0,0 +1,56 @@
+use crate::{error::Result, parse::Line};
+
+/// Inclusive `start..=end` range of ids which are moved by `shift`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+    pub shift: i64,
+}
+
+pub fn parse_range(line: &Line) -> Result<Range> {
+    let mut columns = line.text.split_whitespace();
+    let mut next = |what: &str| match columns.next() {
+        Some(column) => line.parse::<i64>(column, what),
+        None => Err(line.error(line.end(), format!("could not find {what}"))),
+    };
+    let dst_start = next("destination start")?;
+    let src_start = next("source start")?;
+    let len = next("length")?;
+    Ok(Range {
+        start: src_start,
+        end: src_start + len - 1,
+        shift: dst_start - src_start,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        let input = Line {
+            number: 1,
+            text: "50 98 2",
+        };
+        let expected = Range {
+            start: 98,
+            end: 99,
+            shift: -48,
+        };
+        let result = parse_range(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_range_error() {
+        let input = Line {
+            number: 2,
+            text: "50 98",
+        };
+        let error = parse_range(&input).unwrap_err();
+        assert_eq!(error.message, "could not find length");
+        assert_eq!(error.location.unwrap().column, 6);
+    }
+}
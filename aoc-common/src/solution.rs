@@ -0,0 +1,33 @@
+use std::fmt::{self, Display};
+
+use crate::error::Result;
+
+/// A puzzle solution split into a parse phase shared by both parts and a
+/// solve phase per part.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Problems with the input which do not prevent solving it, reported
+    /// alongside the answers.
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Answer of a part which has not been solved yet.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
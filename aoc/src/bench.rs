@@ -0,0 +1,130 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Result, Solution};
+use serde::Serialize;
+
+/// Median time of each phase over the benchmark runs.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Timings {
+    pub day: u8,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part1_ns", serialize_with = "nanos")]
+    pub part1: Duration,
+    #[serde(rename = "part2_ns", serialize_with = "nanos")]
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    runs: u32,
+    days: &'a [Timings],
+}
+
+fn nanos<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+pub fn bench<S: Solution>(input: &str, runs: u32) -> Result<Timings> {
+    let parsed = S::parse(input).map_err(|err| err.with_day(S::DAY))?;
+    let parse = median(runs, || {
+        black_box(S::parse(black_box(input)).ok());
+    });
+    let part1 = median(runs, || {
+        black_box(S::part1(black_box(&parsed)));
+    });
+    let part2 = median(runs, || {
+        black_box(S::part2(black_box(&parsed)));
+    });
+    Ok(Timings {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn median(runs: u32, mut f: impl FnMut()) -> Duration {
+    let mut times = Vec::new();
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        f();
+        times.push(start.elapsed());
+    }
+    times.sort();
+    times[times.len() / 2]
+}
+
+pub fn table(timings: &[Timings]) -> String {
+    let mut out = format!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for t in timings {
+        writeln!(
+            out,
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+            format!("{:02}", t.day),
+            format!("{:.2?}", t.parse),
+            format!("{:.2?}", t.part1),
+            format!("{:.2?}", t.part2),
+            format!("{:.2?}", t.total()),
+        )
+        .unwrap();
+    }
+    out
+}
+
+pub fn json(timings: &[Timings], runs: u32) -> String {
+    let report = Report {
+        runs,
+        days: timings,
+    };
+    serde_json::to_string_pretty(&report).expect("Timings should serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings() -> Vec<Timings> {
+        vec![Timings {
+            day: 7,
+            parse: Duration::from_micros(1500),
+            part1: Duration::from_nanos(250),
+            part2: Duration::from_millis(2),
+        }]
+    }
+
+    #[test]
+    fn test_table() {
+        let expected = "\
+Day         Parse        Part 1        Part 2         Total
+ 07        1.50ms      250.00ns        2.00ms        3.50ms
+";
+        assert_eq!(table(&timings()), expected);
+    }
+
+    #[test]
+    fn test_json() {
+        let result: serde_json::Value = serde_json::from_str(&json(&timings(), 5)).unwrap();
+        let expected = serde_json::json!({
+            "runs": 5,
+            "days": [{"day": 7, "parse_ns": 1500000, "part1_ns": 250, "part2_ns": 2000000}]
+        });
+        assert_eq!(result, expected);
+    }
+}
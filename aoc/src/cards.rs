@@ -0,0 +1,82 @@
+use std::{error::Error, path::PathBuf};
+
+use aoc_common::Solution;
+use clap::{Args, Subcommand};
+use day_04::{
+    explain::explain,
+    parse_cards,
+    scoring::{parse_rule, total_copies, total_points},
+    validate::{validate, Policy},
+    Card, Day04,
+};
+
+use crate::load_with;
+
+#[derive(Args)]
+pub struct CardsArgs {
+    #[command(subcommand)]
+    command: CardsCommand,
+}
+
+#[derive(Subcommand)]
+enum CardsCommand {
+    /// Print the matched numbers, points and copies of every card
+    Explain(CardsInput),
+    /// Print the total points and cards under a scoring rule
+    Score(CardsInput),
+    /// Report duplicate numbers, card id gaps and wins past the last card
+    Validate(CardsInput),
+}
+
+#[derive(Args)]
+struct CardsInput {
+    /// Puzzle input file or `-` for stdin, defaults to the day 04 input
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Scoring rule: doubling, linear, fibonacci, win-previous or capped=N
+    #[arg(long, default_value = "doubling")]
+    rule: String,
+    /// How to handle invalid cards: clamp, error or ignore
+    #[arg(long, default_value = "clamp")]
+    policy: Policy,
+}
+
+pub fn run(args: CardsArgs) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        CardsCommand::Explain(args) => {
+            let rule = parse_rule(&args.rule)?;
+            let cards = parse(&args)?;
+            let reports = explain(&cards, rule.as_ref());
+            for report in &reports {
+                println!("{report}");
+            }
+            let points = total_points(&cards, rule.as_ref());
+            let copies = total_copies(&cards, rule.as_ref());
+            println!("Total: {points} points, {copies} cards");
+        }
+        CardsCommand::Score(args) => {
+            let rule = parse_rule(&args.rule)?;
+            let cards = parse(&args)?;
+            println!("Points: {}", total_points(&cards, rule.as_ref()));
+            println!("Cards: {}", total_copies(&cards, rule.as_ref()));
+        }
+        CardsCommand::Validate(args) => {
+            let cards = parse(&args)?;
+            println!("{} cards checked", cards.len());
+        }
+    }
+    Ok(())
+}
+
+/// Parses and validates the cards, reporting issues on stderr.
+fn parse(args: &CardsInput) -> Result<Vec<Card>, Box<dyn Error>> {
+    let (cards, issues) = load_with(args.input.as_deref(), Day04::DAY, |input| {
+        let (cards, spans): (Vec<_>, Vec<_>) = parse_cards(input)?.into_iter().unzip();
+        let issues = validate(&cards, &spans, args.policy)?;
+        Ok((cards, issues))
+    })?;
+    for issue in issues {
+        eprintln!("warning: {issue}");
+    }
+    Ok(cards)
+}
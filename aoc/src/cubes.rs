@@ -0,0 +1,259 @@
+use std::{collections::BTreeMap, error::Error, fs, path::PathBuf};
+
+use clap::{Args, Subcommand};
+use day_02::{
+    part1::puzzle_bag,
+    stats::{power_histogram, stats},
+    Bag, Day02, Game, Violation,
+};
+use serde_json::json;
+
+use crate::load;
+
+#[derive(Args)]
+pub struct CubesArgs {
+    #[command(subcommand)]
+    command: CubesCommand,
+}
+
+#[derive(Subcommand)]
+enum CubesCommand {
+    /// List the games which are possible with the given bag
+    Possible(PossibleArgs),
+    /// Explain which draws make each impossible game impossible
+    Violations(ViolationsArgs),
+    /// Print per-game and overall cube statistics
+    Stats(StatsArgs),
+}
+
+#[derive(Args)]
+struct CubesInput {
+    /// Puzzle input file or `-` for stdin, defaults to the day 02 input
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BagArgs {
+    /// Cubes of a colour in the bag, e.g. `red=12`, overrides the puzzle bag
+    /// and the limits file
+    #[arg(long = "limit", value_name = "COLOUR=COUNT", value_parser = parse_limit)]
+    limits: Vec<(String, u32)>,
+    /// TOML file with `colour = count` entries overriding the puzzle bag
+    #[arg(long)]
+    limits_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct PossibleArgs {
+    #[command(flatten)]
+    input: CubesInput,
+    #[command(flatten)]
+    bag: BagArgs,
+}
+
+#[derive(Args)]
+struct ViolationsArgs {
+    #[command(flatten)]
+    input: CubesInput,
+    #[command(flatten)]
+    bag: BagArgs,
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    #[command(flatten)]
+    input: CubesInput,
+    /// Width of the power histogram buckets
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(1..))]
+    bucket: u64,
+}
+
+pub fn run(args: CubesArgs) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        CubesCommand::Possible(args) => {
+            let games = load::<Day02>(args.input.input.as_deref())?;
+            let bag = bag(&args.bag)?;
+            warn_unknown_colours(&games, &bag);
+            let possible = games
+                .iter()
+                .filter(|game| game.is_possible(&bag))
+                .map(|game| game.id)
+                .collect::<Vec<_>>();
+            for id in &possible {
+                println!("Game {id}");
+            }
+            println!(
+                "{} of {} games possible, sum of ids {}",
+                possible.len(),
+                games.len(),
+                possible.iter().sum::<u32>()
+            );
+        }
+        CubesCommand::Violations(args) => {
+            let games = load::<Day02>(args.input.input.as_deref())?;
+            let bag = bag(&args.bag)?;
+            let violations = games
+                .iter()
+                .flat_map(|game| game.violations(&bag))
+                .collect::<Vec<_>>();
+            match args.json {
+                true => println!("{}", violations_json(&violations)),
+                false => violations.iter().for_each(|v| println!("{v}")),
+            }
+        }
+        CubesCommand::Stats(args) => {
+            let stats = stats(&load::<Day02>(args.input.input.as_deref())?);
+            for game in &stats.games {
+                println!(
+                    "Game {:>3}: min bag {}, power {}, {} cubes",
+                    game.id, game.min_bag, game.power, game.total
+                );
+            }
+            println!();
+            println!("Smallest bag for every game: {}", stats.min_bag);
+            println!("Cubes seen: {}", stats.total);
+            for (colour, c) in &stats.colours {
+                println!("{colour}: max {}, mean {:.2} per draw", c.max, c.mean);
+            }
+            println!("Powers:");
+            for (start, count) in power_histogram(&stats.games, args.bucket) {
+                let range = format!("{start}-{}", start.saturating_add(args.bucket - 1));
+                println!("{range:>11} {count:>4} {}", "#".repeat(count));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn violations_json(violations: &[Violation]) -> String {
+    let violations = violations
+        .iter()
+        .map(|v| {
+            json!({
+                "game": v.game,
+                "draw": v.draw,
+                "colour": v.colour,
+                "count": v.count,
+                "limit": v.limit,
+                "excess": v.excess(),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&violations).expect("Violations should serialize")
+}
+
+/// The puzzle bag with the limits file and then the `--limit` options
+/// applied on top, colours they do not mention keep their puzzle count.
+fn bag(args: &BagArgs) -> Result<Bag, Box<dyn Error>> {
+    let mut bag = puzzle_bag();
+    if let Some(path) = &args.limits_file {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        let limits =
+            parse_limits_file(&text).map_err(|err| format!("invalid {}: {err}", path.display()))?;
+        for (colour, count) in limits.iter() {
+            bag.set(colour, count);
+        }
+    }
+    for (colour, count) in &args.limits {
+        bag.set(colour, *count);
+    }
+    Ok(bag)
+}
+
+fn parse_limit(s: &str) -> Result<(String, u32), String> {
+    let (colour, count) = s
+        .split_once('=')
+        .ok_or(format!("expected `COLOUR=COUNT`, found `{s}`"))?;
+    let count = count
+        .trim()
+        .parse()
+        .map_err(|_| format!("could not parse count `{count}`"))?;
+    Ok((colour.trim().to_string(), count))
+}
+
+fn parse_limits_file(text: &str) -> Result<Bag, String> {
+    let limits: BTreeMap<String, u32> = toml::from_str(text).map_err(|err| err.to_string())?;
+    let mut bag = Bag::default();
+    for (colour, count) in limits {
+        bag.set(&colour, count);
+    }
+    Ok(bag)
+}
+
+fn warn_unknown_colours(games: &[Game], bag: &Bag) {
+    for game in games {
+        for warning in game.unknown_colour_warnings(bag) {
+            eprintln!("warning: {warning}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_limit() {
+        assert_eq!(parse_limit("red=12"), Ok(("red".to_string(), 12)));
+        assert!(parse_limit("red").is_err());
+        assert!(parse_limit("red=x").is_err());
+    }
+
+    #[test]
+    fn test_parse_limits_file() {
+        let expected = Bag::from([("red", 12), ("green", 13)]);
+        let result = parse_limits_file("red = 12\ngreen = 13\n").unwrap();
+        assert_eq!(result, expected);
+        assert!(parse_limits_file("red = \"many\"").is_err());
+    }
+
+    #[test]
+    fn test_violations_json() {
+        let violations = vec![Violation {
+            game: 3,
+            draw: 1,
+            colour: "red".to_string(),
+            count: 20,
+            limit: 12,
+        }];
+        let result: serde_json::Value =
+            serde_json::from_str(&violations_json(&violations)).unwrap();
+        let expected = json!([
+            {"game": 3, "draw": 1, "colour": "red", "count": 20, "limit": 12, "excess": 8}
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[derive(clap::Parser)]
+    struct BagCli {
+        #[command(flatten)]
+        bag: BagArgs,
+    }
+
+    fn bag_from(args: &[&str]) -> Bag {
+        let cli = <BagCli as clap::Parser>::try_parse_from(["bag"].iter().chain(args)).unwrap();
+        bag(&cli.bag).unwrap()
+    }
+
+    #[test]
+    fn test_bag_overrides() {
+        assert_eq!(bag_from(&[]), puzzle_bag());
+        let expected = Bag::from([("red", 20), ("green", 13), ("blue", 14), ("teal", 1)]);
+        assert_eq!(
+            bag_from(&["--limit", "red=20", "--limit", "teal=1"]),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_bag_limits_example() {
+        // limits repeating the puzzle counts leave the puzzle bag as it is
+        let bag = bag_from(&["--limit", "red=12", "--limit", "green=13"]);
+        assert_eq!(bag, Bag::from([("red", 12), ("green", 13), ("blue", 14)]));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_common::{
+    input::{self, InputSource},
+    Solution,
+};
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod cards;
+mod cubes;
+mod registry;
+mod scaffold;
+mod schematic;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solvers against their puzzle input
+    Run(RunArgs),
+    /// Check every solver against the answers recorded for the real inputs
+    Verify(VerifyArgs),
+    /// Time parse, part 1 and part 2 of every solver on its real input
+    Bench(BenchArgs),
+    /// Create a new day crate from the `day-00` template
+    New(NewArgs),
+    /// Day 04 scratchcard tools
+    Cards(cards::CardsArgs),
+    /// Day 02 cube game tools
+    Cubes(cubes::CubesArgs),
+    /// Day 03 engine schematic tools
+    Schematic(schematic::SchematicArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Part to run, both parts are run when omitted
+    #[arg(long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input file or `-` for stdin, defaults to
+    /// `$AOC_INPUT_DIR/2023/dayNN.txt` with `inputs` as the default directory
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Run every registered solver
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify, every registered solver when omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Manifest of recorded answers
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every registered solver when omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Puzzle input file or `-` for stdin, defaults to the day's real input
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Number of runs per phase, the median is reported
+    #[arg(long, default_value_t = 10)]
+    runs: u32,
+    /// Also write the timings as JSON to this file
+    #[arg(long)]
+    json: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => scaffold::new_day(Path::new("."), args.day),
+        Command::Cards(args) => cards::run(args),
+        Command::Cubes(args) => cubes::run(args),
+        Command::Schematic(args) => schematic::run(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the input of `day` from `input`, or its default file, and parses it
+/// with `parse`, rendering parse errors for the terminal.
+fn load_with<T>(
+    input: Option<&Path>,
+    day: u8,
+    parse: impl FnOnce(&str) -> aoc_common::Result<T>,
+) -> Result<T, Box<dyn Error>> {
+    let text = InputSource::from_arg(input, day).read()?;
+    Ok(parse(&text).map_err(|err| err.with_day(day).render())?)
+}
+
+/// Parsed input of `S`, see [`load_with`].
+fn load<S: Solution>(input: Option<&Path>) -> Result<S::Input, Box<dyn Error>> {
+    load_with(input, S::DAY, S::parse)
+}
+
+/// The solver for `day`, or every registered solver when no day is given.
+fn select(day: Option<u8>) -> Result<Vec<&'static registry::Solver>, String> {
+    match day {
+        Some(day) => Ok(vec![
+            registry::find(day).ok_or(format!("no solver registered for day {day}"))?
+        ]),
+        None => Ok(registry::SOLVERS.iter().collect()),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    let solvers = select(args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for solver in solvers {
+        let input = InputSource::from_arg(args.input.as_deref(), solver.day).read()?;
+        let solved = (solver.solve)(&input, &parts).map_err(|err| err.render())?;
+        for warning in &solved.warnings {
+            eprintln!("warning: day {:02}: {warning}", solver.day);
+        }
+        for (part, answer) in parts.iter().zip(solved.answers) {
+            println!("Day {:02} part {part}: {answer}", solver.day);
+        }
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), Box<dyn Error>> {
+    let solvers = select(args.day)?;
+    let manifest = verify::Manifest::load(&args.answers)?;
+    let dir = input::input_dir();
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solver in solvers {
+        for outcome in verify::verify(solver, &manifest, &dir) {
+            println!("{outcome}");
+            if outcome.is_failure() {
+                failed += 1;
+            } else if outcome.is_missing() {
+                missing += 1;
+            } else {
+                passed += 1;
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(format!("{failed} answers do not match {}", args.answers.display()).into());
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), Box<dyn Error>> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+    let mut timings = Vec::new();
+    for solver in select(args.day)? {
+        let input = InputSource::from_arg(args.input.as_deref(), solver.day).read()?;
+        timings.push((solver.bench)(&input, args.runs).map_err(|err| err.render())?);
+    }
+    print!("{}", bench::table(&timings));
+    if let Some(path) = args.json {
+        fs::write(&path, bench::json(&timings, args.runs))
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
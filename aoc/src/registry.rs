@@ -0,0 +1,79 @@
+use aoc_common::{Result, Solution};
+
+use crate::bench::{self, Timings};
+
+pub struct Solver {
+    pub day: u8,
+    /// Parses the input once and returns the answers of the requested parts.
+    pub solve: fn(&str, &[u8]) -> Result<Solved>,
+    /// Times parse, part 1 and part 2 separately over the given number of runs.
+    pub bench: fn(&str, u32) -> Result<Timings>,
+}
+
+impl Solver {
+    const fn new<S: Solution>() -> Self {
+        Solver {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+pub struct Solved {
+    pub answers: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved> {
+    let input = S::parse(input).map_err(|err| err.with_day(S::DAY))?;
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&input).to_string(),
+            _ => S::part2(&input).to_string(),
+        })
+        .collect();
+    Ok(Solved {
+        answers,
+        warnings: S::warnings(&input),
+    })
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<day_01::Day01>(),
+    Solver::new::<day_02::Day02>(),
+    Solver::new::<day_03::Day03>(),
+    Solver::new::<day_04::Day04>(),
+    Solver::new::<day_05::Day05>(),
+    Solver::new::<day_06::Day06>(),
+    Solver::new::<day_07::Day07>(),
+    Solver::new::<day_08::Day08>(),
+    Solver::new::<day_09::Day09>(),
+    Solver::new::<day_11::Day11>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_unique() {
+        for (i, a) in SOLVERS.iter().enumerate() {
+            for b in &SOLVERS[i + 1..] {
+                assert_ne!(a.day, b.day);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let solver = find(7).expect("Day 7 is registered");
+        assert_eq!(solver.day, 7);
+        assert!(find(10).is_none());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{error::Error, fs, path::Path};
+
+use aoc_common::input::{day_path, input_dir};
+
+const TEMPLATE: &str = "day-00";
+const TEMPLATE_FILES: &[&str] = &["src/lib.rs", "src/part1.rs", "src/part2.rs"];
+
+/// Creates `day-NN` from the `day-00` template and registers it in the
+/// workspace, the runner's dependencies and the solver registry.
+pub fn new_day(root: &Path, day: u8) -> Result<(), Box<dyn Error>> {
+    let name = format!("day-{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let registry = root.join("aoc/src/registry.rs");
+    let members = add_member(&read(&manifest)?, day)?;
+    let dependencies = add_dependency(&read(&runner_manifest)?, day)?;
+    let solvers = add_solver(&read(&registry)?, day)?;
+
+    let template = root.join(TEMPLATE);
+    let crate_manifest = render_manifest(&read(&template.join("Cargo.toml"))?, day);
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), crate_manifest)?;
+    for file in TEMPLATE_FILES {
+        fs::write(
+            dir.join(file),
+            render_source(&read(&template.join(file))?, day),
+        )?;
+    }
+    fs::write(&manifest, members)?;
+    fs::write(&runner_manifest, dependencies)?;
+    fs::write(&registry, solvers)?;
+
+    println!(
+        "Created {name}, put the puzzle input in {}",
+        day_path(&input_dir(), day).display()
+    );
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn render_manifest(template: &str, day: u8) -> String {
+    template
+        .lines()
+        .map(|line| match line {
+            l if l.starts_with("name = ") => format!("name = \"day-{day:02}\""),
+            l if l.starts_with("version = ") => "version.workspace = true".to_string(),
+            l if l.starts_with("edition = ") => "edition.workspace = true".to_string(),
+            l if l.starts_with("aoc-common = ") => "aoc-common.workspace = true".to_string(),
+            l => l.to_string(),
+        })
+        .map(|line| line + "\n")
+        .collect()
+}
+
+fn render_source(template: &str, day: u8) -> String {
+    template
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    insert_sorted(
+        manifest,
+        |line| line.trim_start().starts_with("\"day-"),
+        format!("\"day-{day:02}\","),
+    )
+}
+
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    insert_sorted(
+        manifest,
+        |line| line.starts_with("day-"),
+        format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}"),
+    )
+}
+
+fn add_solver(registry: &str, day: u8) -> Result<String, String> {
+    insert_sorted(
+        registry,
+        |line| line.trim_start().starts_with("Solver::new::<day_"),
+        format!("Solver::new::<day_{day:02}::Day{day:02}>(),"),
+    )
+}
+
+/// Inserts `entry` into the first block of consecutive lines matching
+/// `is_entry`, keeping the block sorted and reusing its indentation.
+fn insert_sorted(
+    text: &str,
+    is_entry: impl Fn(&str) -> bool,
+    entry: String,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| is_entry(line))
+        .ok_or(format!("could not find where to register `{entry}`"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|line| is_entry(line))
+            .count();
+    let block = &lines[start..end];
+    if block.iter().any(|line| line.trim() == entry) {
+        return Err(format!("`{entry}` is already registered"));
+    }
+
+    let indent = &block[0][..block[0].len() - block[0].trim_start().len()];
+    let line = format!("{indent}{entry}");
+    let index = start
+        + block
+            .iter()
+            .take_while(|line| line.trim() < entry.as_str())
+            .count();
+    lines.insert(index, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-11\",\n]\n";
+        let expected = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-10\",\n    \"day-11\",\n]\n";
+        let result = add_member(manifest, 10).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest =
+            "[dependencies]\nclap = \"4.5\"\nday-01 = { path = \"../day-01\" }\nserde = \"1.0\"\n";
+        let expected = "[dependencies]\nclap = \"4.5\"\nday-01 = { path = \"../day-01\" }\nday-12 = { path = \"../day-12\" }\nserde = \"1.0\"\n";
+        let result = add_dependency(manifest, 12).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_solver() {
+        let registry =
+            "pub const SOLVERS: &[Solver] = &[\n    Solver::new::<day_01::Day01>(),\n];\n";
+        let expected = "pub const SOLVERS: &[Solver] = &[\n    Solver::new::<day_01::Day01>(),\n    Solver::new::<day_12::Day12>(),\n];\n";
+        let result = add_solver(registry, 12).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_solver_twice() {
+        let registry = "&[\n    Solver::new::<day_01::Day01>(),\n];\n";
+        let result = add_solver(registry, 1).unwrap_err();
+        assert_eq!(
+            result,
+            "`Solver::new::<day_01::Day01>(),` is already registered"
+        );
+    }
+
+    #[test]
+    fn test_render_template() {
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day-00");
+        let manifest = render_manifest(&read(&template.join("Cargo.toml")).unwrap(), 12);
+        assert!(manifest.contains("name = \"day-12\"\nversion.workspace = true\n"));
+        assert!(manifest.contains("aoc-common.workspace = true\n"));
+        let lib = render_source(&read(&template.join("src/lib.rs")).unwrap(), 12);
+        assert!(lib.contains("pub struct Day12;"));
+        assert!(lib.contains("const DAY: u8 = 12;"));
+        assert!(!lib.contains("00"));
+    }
+}
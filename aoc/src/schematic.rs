@@ -0,0 +1,217 @@
+use std::{error::Error, path::PathBuf};
+
+use clap::{builder::RangedU64ValueParser, Args, Subcommand, ValueEnum};
+use day_03::{
+    gears::{Count, GearRule, Reducer},
+    generate::generate,
+    graph::{graph, Graph},
+    neighbourhood::Neighbourhood,
+    render::{ansi, html},
+    Day03, Schematic,
+};
+use serde_json::json;
+
+use crate::load;
+
+#[derive(Args)]
+pub struct SchematicArgs {
+    #[command(subcommand)]
+    command: SchematicCommand,
+}
+
+#[derive(Subcommand)]
+enum SchematicCommand {
+    /// Sum the ratios of the gears found with a custom gear rule
+    Gears(GearsArgs),
+    /// Print the schematic with parts, orphan numbers and gears highlighted
+    Render(RenderArgs),
+    /// Export the graph linking numbers to the symbols they touch
+    Graph(GraphArgs),
+    /// Print a random schematic, e.g. a large one for `aoc bench --day 3 --input -`
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct SchematicInput {
+    /// Puzzle input file or `-` for stdin, defaults to the day 03 input
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Cells counting as adjacent: 4, 8 or radius=K
+    #[arg(long, default_value = "8")]
+    neighbourhood: Neighbourhood,
+}
+
+#[derive(Args)]
+struct RuleArgs {
+    /// Symbol acting as a gear
+    #[arg(long, default_value_t = '*')]
+    symbol: char,
+    /// Gears need exactly this many adjacent numbers
+    #[arg(
+        long,
+        default_value_t = 2,
+        conflicts_with = "at_least",
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    exactly: usize,
+    /// Gears need at least this many adjacent numbers
+    #[arg(long, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    at_least: Option<usize>,
+    /// How adjacent numbers are combined: product, sum or max
+    #[arg(long, default_value = "product")]
+    reduce: Reducer,
+}
+
+impl RuleArgs {
+    fn rule(&self) -> GearRule {
+        GearRule {
+            symbol: self.symbol,
+            count: match self.at_least {
+                Some(n) => Count::AtLeast(n),
+                None => Count::Exactly(self.exactly),
+            },
+            reducer: self.reduce,
+        }
+    }
+}
+
+#[derive(Args)]
+struct GearsArgs {
+    #[command(flatten)]
+    input: SchematicInput,
+    #[command(flatten)]
+    rule: RuleArgs,
+    /// Print every gear with its numbers and ratio
+    #[arg(long)]
+    list: bool,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    #[command(flatten)]
+    input: SchematicInput,
+    #[command(flatten)]
+    rule: RuleArgs,
+    /// Write a standalone HTML page instead of ANSI colours
+    #[arg(long)]
+    html: bool,
+}
+
+#[derive(Args)]
+struct GraphArgs {
+    #[command(flatten)]
+    input: SchematicInput,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Dot)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[arg(long, default_value_t = 1000)]
+    width: usize,
+    #[arg(long, default_value_t = 1000)]
+    height: usize,
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Dot,
+    Json,
+}
+
+pub fn run(args: SchematicArgs) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        SchematicCommand::Gears(args) => {
+            let schematic = parse(&args.input)?;
+            let rule = args.rule.rule();
+            if args.list {
+                for (gear, numbers) in rule.gears(&schematic) {
+                    let values = numbers
+                        .iter()
+                        .map(|sn| sn.value.to_string())
+                        .collect::<Vec<_>>();
+                    println!(
+                        "{} at ({}, {}): {} -> {}",
+                        gear.char,
+                        gear.coords.x,
+                        gear.coords.y,
+                        values.join(" "),
+                        rule.ratio(&numbers)
+                    );
+                }
+            }
+            println!("Total: {}", rule.total(&schematic));
+        }
+        SchematicCommand::Render(args) => {
+            let schematic = parse(&args.input)?;
+            let rule = args.rule.rule();
+            match args.html {
+                true => print!("{}", html(&schematic, &rule)),
+                false => print!("{}", ansi(&schematic, &rule)),
+            }
+        }
+        SchematicCommand::Graph(args) => {
+            let graph = graph(&parse(&args.input)?);
+            match args.format {
+                Format::Dot => print!("{}", graph.dot()),
+                Format::Json => println!("{}", graph_json(&graph)),
+            }
+        }
+        SchematicCommand::Generate(args) => {
+            print!("{}", generate(args.width, args.height, args.seed));
+        }
+    }
+    Ok(())
+}
+
+fn graph_json(graph: &Graph) -> String {
+    let numbers = graph
+        .numbers
+        .iter()
+        .zip(graph.number_degrees())
+        .map(|(n, degree)| json!({"value": n.value, "x": n.coords.x, "y": n.coords.y, "degree": degree}))
+        .collect::<Vec<_>>();
+    let symbols = graph
+        .symbols
+        .iter()
+        .zip(graph.symbol_degrees())
+        .map(|(s, degree)| json!({"char": s.char, "x": s.coords.x, "y": s.coords.y, "degree": degree}))
+        .collect::<Vec<_>>();
+    let edges = graph
+        .edges
+        .iter()
+        .map(|(n, s)| json!({"number": n, "symbol": s}))
+        .collect::<Vec<_>>();
+    let graph = json!({"numbers": numbers, "symbols": symbols, "edges": edges});
+    serde_json::to_string_pretty(&graph).expect("Graph should serialize")
+}
+
+fn parse(args: &SchematicInput) -> Result<Schematic, Box<dyn Error>> {
+    let schematic = load::<Day03>(args.input.as_deref())?;
+    Ok(schematic.with_neighbourhood(args.neighbourhood))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_graph_json() {
+        let schematic = Day03::parse("1*.\n..7").unwrap();
+        let result: serde_json::Value =
+            serde_json::from_str(&graph_json(&graph(&schematic))).unwrap();
+        let expected = json!({
+            "numbers": [
+                {"value": 1, "x": 0, "y": 0, "degree": 1},
+                {"value": 7, "x": 2, "y": 1, "degree": 1}
+            ],
+            "symbols": [{"char": "*", "x": 1, "y": 0, "degree": 2}],
+            "edges": [{"number": 0, "symbol": 0}, {"number": 1, "symbol": 0}]
+        });
+        assert_eq!(result, expected);
+    }
+}
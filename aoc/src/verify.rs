@@ -0,0 +1,176 @@
+use std::{collections::BTreeMap, error::Error, fmt, fs, path::Path};
+
+use aoc_common::input::day_path;
+use serde::Deserialize;
+
+use crate::registry::Solver;
+
+/// Recorded answers keyed by day, read from `answers.toml`:
+///
+/// ```toml
+/// [day07]
+/// part1 = 253205868
+/// part2 = 253907829
+/// ```
+#[derive(Debug)]
+pub struct Manifest {
+    days: BTreeMap<u8, Answers>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Answers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        Ok(Self::parse(&text).map_err(|err| format!("invalid {}: {err}", path.display()))?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let tables: BTreeMap<String, Answers> = toml::from_str(text).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+        for (key, answers) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or(format!("expected a `dayNN` table, found `{key}`"))?;
+            days.insert(day, answers);
+        }
+        Ok(Manifest { days })
+    }
+
+    /// The recorded answer, integers and strings are both accepted.
+    pub fn answer(&self, day: u8, part: u8) -> Option<String> {
+        let answers = self.days.get(&day)?;
+        let value = match part {
+            1 => answers.part1.as_ref()?,
+            _ => answers.part2.as_ref()?,
+        };
+        Some(match value {
+            toml::Value::String(s) => s.clone(),
+            v => v.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No answer is recorded, the computed one is kept for reference.
+    MissingAnswer {
+        actual: String,
+    },
+    MissingInput,
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match &self.status {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL expected {expected}, got {actual}")
+            }
+            Status::MissingAnswer { actual } => write!(f, "missing answer (got {actual})"),
+            Status::MissingInput => write!(f, "missing input"),
+            Status::Error(err) => write!(f, "ERROR {err}"),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.status,
+            Status::MissingAnswer { .. } | Status::MissingInput
+        )
+    }
+}
+
+/// Runs the solver on its input from `dir` and compares both parts with the manifest.
+pub fn verify(solver: &Solver, manifest: &Manifest, dir: &Path) -> Vec<Outcome> {
+    let outcome = |part, status| Outcome {
+        day: solver.day,
+        part,
+        status,
+    };
+    let Ok(input) = fs::read_to_string(day_path(dir, solver.day)) else {
+        return [1, 2]
+            .map(|part| outcome(part, Status::MissingInput))
+            .into();
+    };
+    let answers = match (solver.solve)(&input, &[1, 2]) {
+        Ok(solved) => solved.answers,
+        Err(err) => {
+            return [1, 2]
+                .map(|part| outcome(part, Status::Error(err.to_string())))
+                .into()
+        }
+    };
+    (1..)
+        .zip(answers)
+        .map(|(part, actual)| {
+            let status = match manifest.answer(solver.day, part) {
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Fail { expected, actual },
+                None => Status::MissingAnswer { actual },
+            };
+            outcome(part, status)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::registry::SOLVERS;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = Manifest::parse("[day07]\npart1 = 6440\npart2 = \"5905\"\n").unwrap();
+        assert_eq!(manifest.answer(7, 1).as_deref(), Some("6440"));
+        assert_eq!(manifest.answer(7, 2).as_deref(), Some("5905"));
+        assert_eq!(manifest.answer(8, 1), None);
+    }
+
+    #[test]
+    fn test_parse_manifest_error() {
+        let result = Manifest::parse("[seven]\npart1 = 6440\n").unwrap_err();
+        assert_eq!(result, "expected a `dayNN` table, found `seven`");
+        assert!(Manifest::parse("[day07]\npart3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_real_inputs_match_answers() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        let manifest = Manifest::load(&root.join("answers.toml")).unwrap();
+        for solver in SOLVERS {
+            for outcome in verify(solver, &manifest, &root.join("inputs")) {
+                assert!(!outcome.is_failure(), "{outcome}");
+            }
+        }
+    }
+}
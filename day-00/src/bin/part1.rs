@@ -1,22 +0,0 @@
-fn main() {
-    let input = std::fs::read_to_string("./input.txt").unwrap();
-    let output = part1(input);
-    dbg!(output);
-}
-
-fn part1(input: String) -> String {
-    todo!()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let input = "".to_string();
-        let expected = "".to_string();
-        let result = part1(input);
-        assert_eq!(result, expected);
-    }
-}
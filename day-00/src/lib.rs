@@ -0,0 +1,26 @@
+use aoc_common::{Result, Solution, Unsolved};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
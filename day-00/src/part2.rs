@@ -0,0 +1,20 @@
+use aoc_common::Unsolved;
+
+pub fn part2(_input: &str) -> Unsolved {
+    Unsolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day00;
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = "";
+        let expected = Unsolved;
+        let result = part2(&Day00::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_common::{parse::lines, Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Digits one to nine spelled out, as read by part 2.
+pub const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input)
+            .map(|line| match has_digit(line.text) {
+                true => Ok(line.text.to_string()),
+                false => Err(line.error(line.text, "expected a digit or a spelled-out digit")),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn has_digit(line: &str) -> bool {
+    line.chars().any(|c| c.is_ascii_digit()) || SPELLED.iter().any(|s| line.contains(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "two1nine\neightwothree\nabcxyz";
+        let error = Day01::parse(input).unwrap_err();
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (3, 1));
+        assert_eq!(error.message, "expected a digit or a spelled-out digit");
+    }
+}
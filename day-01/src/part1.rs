@@ -0,0 +1,45 @@
+/// Calibration value from the first and last digit, 0 when the line only
+/// has spelled-out digits.
+fn transform(line: &str) -> u32 {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    match digits.next() {
+        Some(first) => first * 10 + digits.next_back().unwrap_or(first),
+        None => 0,
+    }
+}
+
+pub fn part1(lines: &[String]) -> u32 {
+    lines.iter().map(|line| transform(line)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_transform() {
+        let cases = vec![
+            ("1abc2", 12),
+            ("pqr3stu8vwx", 38),
+            ("treb7uchet", 77),
+            ("eightwothree", 0),
+        ];
+        for (line, expected) in cases {
+            let result = transform(line);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        let expected = 142;
+        let result = part1(&Day01::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
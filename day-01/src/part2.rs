@@ -0,0 +1,67 @@
+use crate::SPELLED;
+use std::collections::HashMap;
+
+fn decode_first(line: &str, mapping: &HashMap<&str, u32>) -> u32 {
+    let mut buff: String = String::new();
+    for c in line.chars() {
+        if c.is_ascii_digit() {
+            return c.to_digit(10).unwrap();
+        }
+        buff.push(c);
+        let key = mapping.keys().find(|k| buff.contains(k.to_owned()));
+        if let Some(k) = key {
+            return mapping.get(k).unwrap().to_owned();
+        }
+    }
+    0
+}
+
+fn decode_last(line: &str, mapping: &HashMap<&str, u32>) -> u32 {
+    let mut buff: String = String::new();
+    for c in line.chars().rev() {
+        if c.is_ascii_digit() {
+            return c.to_digit(10).unwrap();
+        }
+        buff.push(c);
+        let key = mapping
+            .keys()
+            .find(|k| buff.contains(&reverse(k.to_owned())));
+        if let Some(k) = key {
+            return mapping.get(k).unwrap().to_owned();
+        }
+    }
+    0
+}
+
+fn reverse(string: &str) -> String {
+    string.chars().rev().collect::<String>()
+}
+
+pub fn part2(lines: &[String]) -> u32 {
+    let mapping: HashMap<&str, u32> = SPELLED.into_iter().zip(1..).collect();
+    lines
+        .iter()
+        .map(|l| 10 * decode_first(l, &mapping) + decode_last(l, &mapping))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        let expected = 281;
+        let result = part2(&Day01::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,365 @@
+use aoc_common::{
+    parse::{lines, Line},
+    Result, Solution,
+};
+use std::{collections::BTreeMap, fmt};
+
+pub mod part1;
+pub mod part2;
+pub mod stats;
+
+/// Number of cubes of each colour.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+/// Cubes revealed at once during a game.
+pub type Draw = Cubes;
+
+/// Cubes loaded in the bag.
+pub type Bag = Cubes;
+
+impl Cubes {
+    /// Count of `colour`, 0 when it is absent.
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, colour: &str, count: u32) {
+        self.counts.insert(colour.to_string(), count);
+    }
+
+    pub fn contains(&self, colour: &str) -> bool {
+        self.counts.contains_key(colour)
+    }
+
+    /// Colours and counts in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    /// Whether the bag holds enough cubes for `draw`. Colours missing from
+    /// the bag are never allowed.
+    pub fn allows(&self, draw: &Draw) -> bool {
+        draw.iter()
+            .all(|(colour, count)| match self.counts.get(colour) {
+                Some(limit) => count <= *limit,
+                None => false,
+            })
+    }
+
+    /// Product of the counts of every colour, saturating at `u64::MAX`.
+    pub fn power(&self) -> u64 {
+        self.counts
+            .values()
+            .fold(1, |power, count| power.saturating_mul(u64::from(*count)))
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = self
+            .iter()
+            .map(|(colour, count)| format!("{count} {colour}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl<const N: usize> From<[(&str, u32); N]> for Cubes {
+    fn from(counts: [(&str, u32); N]) -> Self {
+        Cubes {
+            counts: counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+}
+
+/// A colour drawn in larger numbers than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub game: u32,
+    /// 1-based index of the draw within the game.
+    pub draw: usize,
+    pub colour: String,
+    pub count: u32,
+    /// Cubes of that colour in the bag, 0 for colours missing from the bag.
+    pub limit: u32,
+}
+
+impl Violation {
+    pub fn excess(&self) -> u32 {
+        self.count - self.limit
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}, draw {}: {} {} exceeds the limit of {} by {}",
+            self.game,
+            self.draw,
+            self.count,
+            self.colour,
+            self.limit,
+            self.excess()
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| bag.allows(draw))
+    }
+
+    /// Every draw and colour which makes the game impossible with `bag`.
+    pub fn violations(&self, bag: &Bag) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for (i, draw) in self.draws.iter().enumerate() {
+            for (colour, count) in draw.iter() {
+                let limit = bag.get(colour);
+                if count > limit || !bag.contains(colour) {
+                    violations.push(Violation {
+                        game: self.id,
+                        draw: i + 1,
+                        colour: colour.to_string(),
+                        count,
+                        limit,
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Colours drawn in this game that the bag does not know about.
+    pub fn unknown_colours<'a>(&'a self, bag: &'a Bag) -> Vec<&'a str> {
+        let mut unknown = Vec::new();
+        for (colour, _) in self.draws.iter().flat_map(Draw::iter) {
+            if !bag.contains(colour) && !unknown.contains(&colour) {
+                unknown.push(colour);
+            }
+        }
+        unknown
+    }
+
+    /// Warnings for the colours of this game missing from `bag`.
+    pub fn unknown_colour_warnings(&self, bag: &Bag) -> Vec<String> {
+        self.unknown_colours(bag)
+            .into_iter()
+            .map(|colour| format!("game {}: colour `{colour}` is not in the bag", self.id))
+            .collect()
+    }
+
+    /// Smallest bag which makes the game possible. Puzzle colours the game
+    /// never draws are kept at 0, so they zero its power.
+    pub fn min_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        for (colour, _) in part1::puzzle_bag().iter() {
+            bag.set(colour, 0);
+        }
+        for (colour, count) in self.draws.iter().flat_map(Draw::iter) {
+            if count >= bag.get(colour) {
+                bag.set(colour, count);
+            }
+        }
+        bag
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input).map(|line| parse_game(&line)).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+
+    /// Games drawing colours missing from the puzzle bag, which part 1 counts
+    /// as impossible.
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        let bag = part1::puzzle_bag();
+        input
+            .iter()
+            .flat_map(|game| game.unknown_colour_warnings(&bag))
+            .collect()
+    }
+}
+
+fn parse_draw(line: &Line, s: &str) -> Result<Draw> {
+    let mut draw = Draw::default();
+    for cubes in s.split(',') {
+        let (amount, colour) = cubes
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| line.error(cubes, "expected `AMOUNT COLOUR`"))?;
+        let amount: u32 = line.parse(amount, "amount")?;
+        let colour = colour.trim();
+        if draw.contains(colour) {
+            return Err(line.error(colour, format!("duplicate colour `{colour}`")));
+        }
+        draw.set(colour, amount);
+    }
+    Ok(draw)
+}
+
+fn parse_game(line: &Line) -> Result<Game> {
+    let (header, body) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.error(line.text, "expected `Game ID: DRAWS`"))?;
+    let id = header
+        .strip_prefix("Game ")
+        .ok_or_else(|| line.error(header, "expected `Game ID`"))?;
+    let id: u32 = line.parse(id, "game id")?;
+    let draws = body
+        .split(';')
+        .map(|s| parse_draw(line, s))
+        .collect::<Result<_>>()?;
+    Ok(Game { id, draws })
+}
+
+/// Example from the puzzle description, shared by the tests.
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_draw() {
+        let input = Line {
+            number: 1,
+            text: " 3 blue, 4 red, 1 purple",
+        };
+        let expected = Draw::from([("blue", 3), ("red", 4), ("purple", 1)]);
+        let result = parse_draw(&input, input.text).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_draw_duplicate() {
+        let input = Line {
+            number: 1,
+            text: " 3 blue, 4 blue",
+        };
+        let location = parse_draw(&input, input.text)
+            .unwrap_err()
+            .location
+            .unwrap();
+        assert_eq!((location.column, location.width), (12, 4));
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let input = Line {
+            number: 1,
+            text: "Game 11: 1 red, 2 green; 2 blue",
+        };
+        let expected = Game {
+            id: 11,
+            draws: vec![
+                Draw::from([("red", 1), ("green", 2)]),
+                Draw::from([("blue", 2)]),
+            ],
+        };
+        let result = parse_game(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_game_error() {
+        let input = "Game 1: 3 blue
+Game 2: 1 blue, x green";
+        let location = Day02::parse(input).unwrap_err().location.unwrap();
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 17);
+        assert_eq!(location.text, "Game 2: 1 blue, x green");
+    }
+
+    #[test]
+    fn test_violations() {
+        let line = Line {
+            number: 1,
+            text: "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        };
+        let game = parse_game(&line).unwrap();
+        let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+        let result = game.violations(&bag);
+        let expected = vec![Violation {
+            game: 3,
+            draw: 1,
+            colour: "red".to_string(),
+            count: 20,
+            limit: 12,
+        }];
+        assert_eq!(result, expected);
+        assert_eq!(
+            result[0].to_string(),
+            "Game 3, draw 1: 20 red exceeds the limit of 12 by 8"
+        );
+        let unknown = game.violations(&Bag::from([("red", 20), ("green", 13)]));
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown[0].excess(), 6);
+    }
+
+    #[test]
+    fn test_unknown_colours() {
+        let line = Line {
+            number: 1,
+            text: "Game 1: 1 red, 2 purple; 3 teal, 1 purple",
+        };
+        let game = parse_game(&line).unwrap();
+        let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(game.unknown_colours(&bag), vec!["purple", "teal"]);
+        assert!(!game.is_possible(&bag));
+        let warnings = Day02::warnings(&vec![game]);
+        assert_eq!(
+            warnings,
+            vec![
+                "game 1: colour `purple` is not in the bag",
+                "game 1: colour `teal` is not in the bag"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_power() {
+        let bag = Bag::from([("red", 20), ("green", 13), ("blue", 6)]);
+        assert_eq!(bag.power(), 1560);
+        let many = Bag::from([("a", 900), ("b", 900), ("c", 900), ("d", 900)]);
+        assert_eq!(many.power(), 656_100_000_000);
+        let huge = Bag::from([("a", u32::MAX), ("b", u32::MAX), ("c", 2)]);
+        assert_eq!(huge.power(), u64::MAX);
+    }
+}
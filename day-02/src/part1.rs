@@ -0,0 +1,29 @@
+use crate::{Bag, Game};
+
+/// Bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+pub fn puzzle_bag() -> Bag {
+    Bag::from([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+pub fn part1(games: &[Game]) -> u32 {
+    let bag = puzzle_bag();
+    games
+        .iter()
+        .filter(|game| game.is_possible(&bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 8;
+        let result = part1(&Day02::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+}
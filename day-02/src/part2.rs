@@ -0,0 +1,28 @@
+use crate::Game;
+
+pub fn part2(games: &[Game]) -> u64 {
+    games.iter().fold(0, |total, game| {
+        total.saturating_add(game.min_bag().power())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 2286;
+        let result = part2(&Day02::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_missing_colour() {
+        // no green cube is drawn, so the game has no power as in the puzzle
+        let result = part2(&Day02::parse("Game 1: 3 red, 4 blue").unwrap());
+        assert_eq!(result, 0);
+    }
+}
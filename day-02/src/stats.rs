@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use crate::{Bag, Draw, Game};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColourStats {
+    /// Most cubes of the colour in a single draw.
+    pub max: u32,
+    /// Cubes of the colour per draw, draws without it count as 0.
+    pub mean: f64,
+    /// Cubes of the colour over all draws, saturating at `u64::MAX`.
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameStats {
+    pub id: u32,
+    pub min_bag: Bag,
+    pub power: u64,
+    pub colours: BTreeMap<String, ColourStats>,
+    /// Cubes seen over all draws of the game.
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub games: Vec<GameStats>,
+    /// Smallest bag which makes every game possible.
+    pub min_bag: Bag,
+    pub colours: BTreeMap<String, ColourStats>,
+    pub total: u64,
+}
+
+pub fn colour_stats<'a>(
+    draws: impl IntoIterator<Item = &'a Draw>,
+) -> BTreeMap<String, ColourStats> {
+    let mut stats: BTreeMap<String, ColourStats> = BTreeMap::new();
+    let mut n_draws = 0;
+    for draw in draws {
+        n_draws += 1;
+        for (colour, count) in draw.iter() {
+            let entry = stats.entry(colour.to_string()).or_insert(ColourStats {
+                max: 0,
+                mean: 0.0,
+                total: 0,
+            });
+            entry.max = entry.max.max(count);
+            entry.total = entry.total.saturating_add(count.into());
+        }
+    }
+    for entry in stats.values_mut() {
+        entry.mean = entry.total as f64 / n_draws as f64;
+    }
+    stats
+}
+
+pub fn game_stats(game: &Game) -> GameStats {
+    let colours = colour_stats(&game.draws);
+    let min_bag = game.min_bag();
+    GameStats {
+        id: game.id,
+        power: min_bag.power(),
+        min_bag,
+        total: total(&colours),
+        colours,
+    }
+}
+
+pub fn stats(games: &[Game]) -> Stats {
+    let colours = colour_stats(games.iter().flat_map(|game| &game.draws));
+    let min_bag = colours
+        .iter()
+        .map(|(colour, stats)| (colour.as_str(), stats.max))
+        .fold(Bag::default(), |mut bag, (colour, max)| {
+            bag.set(colour, max);
+            bag
+        });
+    Stats {
+        games: games.iter().map(game_stats).collect(),
+        min_bag,
+        total: total(&colours),
+        colours,
+    }
+}
+
+fn total(colours: &BTreeMap<String, ColourStats>) -> u64 {
+    colours
+        .values()
+        .fold(0, |total, c| total.saturating_add(c.total))
+}
+
+/// Number of games per power range, keyed by the start of each `bucket` wide range.
+pub fn power_histogram(games: &[GameStats], bucket: u64) -> BTreeMap<u64, usize> {
+    let mut histogram = BTreeMap::new();
+    for game in games {
+        *histogram.entry(game.power / bucket * bucket).or_insert(0) += 1;
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_game_stats() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let result = game_stats(&games[0]);
+        assert_eq!(
+            result.min_bag,
+            Bag::from([("blue", 6), ("green", 2), ("red", 4)])
+        );
+        assert_eq!(result.power, 48);
+        assert_eq!(result.total, 18);
+        let expected = ColourStats {
+            max: 6,
+            mean: 3.0,
+            total: 9,
+        };
+        assert_eq!(result.colours["blue"], expected);
+    }
+
+    #[test]
+    fn test_stats() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let result = stats(&games);
+        assert_eq!(
+            result.min_bag,
+            Bag::from([("blue", 15), ("green", 13), ("red", 20)])
+        );
+        assert_eq!(result.total, 159);
+        assert_eq!(result.games.iter().map(|g| g.power).sum::<u64>(), 2286);
+        assert!(games.iter().all(|g| g.is_possible(&result.min_bag)));
+    }
+
+    #[test]
+    fn test_large_totals() {
+        let games = Day02::parse("Game 1: 4294967295 red; 1 red").unwrap();
+        let result = stats(&games);
+        assert_eq!(result.total, 4294967296);
+        assert_eq!(result.games[0].total, 4294967296);
+        assert_eq!(result.colours["red"].total, 4294967296);
+    }
+
+    #[test]
+    fn test_power_histogram() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let result = power_histogram(&stats(&games).games, 100);
+        let expected = BTreeMap::from([(0, 3), (600, 1), (1500, 1)]);
+        assert_eq!(result, expected);
+    }
+}
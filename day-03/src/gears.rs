@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use crate::{Schematic, SerialNumber, Symbol};
+
+/// How many adjacent numbers a symbol needs to count as a gear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Count {
+    pub fn matches(self, n: usize) -> bool {
+        match self {
+            Count::Exactly(count) => n == count,
+            Count::AtLeast(count) => n >= count,
+        }
+    }
+}
+
+/// How the numbers adjacent to a gear are combined into its ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reducer {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Reducer {
+    /// Combines the values, saturating at `u64::MAX`. No values at all give 0
+    /// whatever the reducer.
+    pub fn reduce(self, values: impl IntoIterator<Item = u32>) -> u64 {
+        let mut values = values.into_iter().map(u64::from);
+        let Some(first) = values.next() else {
+            return 0;
+        };
+        match self {
+            Reducer::Product => values.fold(first, u64::saturating_mul),
+            Reducer::Sum => values.fold(first, u64::saturating_add),
+            Reducer::Max => values.fold(first, u64::max),
+        }
+    }
+}
+
+impl FromStr for Reducer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Reducer::Product),
+            "sum" => Ok(Reducer::Sum),
+            "max" => Ok(Reducer::Max),
+            _ => Err(format!("unknown reducer `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbol: char,
+    pub count: Count,
+    pub reducer: Reducer,
+}
+
+impl GearRule {
+    /// A `*` next to exactly two numbers, whose ratio is their product.
+    pub const PUZZLE: GearRule = GearRule {
+        symbol: '*',
+        count: Count::Exactly(2),
+        reducer: Reducer::Product,
+    };
+
+    /// Symbols qualifying as gears together with their adjacent numbers.
+    pub fn gears<'a>(
+        &'a self,
+        schematic: &'a Schematic,
+    ) -> impl Iterator<Item = (&'a Symbol, Vec<&'a SerialNumber>)> {
+        schematic
+            .symbols_matching(move |c| c == self.symbol)
+            .map(|symbol| (symbol, schematic.adjacent_numbers(symbol)))
+            .filter(|(_, numbers)| self.count.matches(numbers.len()))
+    }
+
+    pub fn ratio(&self, numbers: &[&SerialNumber]) -> u64 {
+        self.reducer.reduce(numbers.iter().map(|sn| sn.value))
+    }
+
+    /// Sum of the ratios of every gear, saturating at `u64::MAX`.
+    pub fn total(&self, schematic: &Schematic) -> u64 {
+        self.gears(schematic)
+            .map(|(_, numbers)| self.ratio(&numbers))
+            .fold(0, u64::saturating_add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_puzzle_rule() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(GearRule::PUZZLE.total(&schematic), 467835);
+    }
+
+    #[test]
+    fn test_counts() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let rule = |count| GearRule {
+            symbol: '*',
+            count,
+            reducer: Reducer::Sum,
+        };
+        // `*` symbols are next to 467 and 35, 617 alone, and 755 and 598
+        assert_eq!(rule(Count::Exactly(1)).total(&schematic), 617);
+        assert_eq!(
+            rule(Count::AtLeast(1)).total(&schematic),
+            467 + 35 + 617 + 755 + 598
+        );
+        assert_eq!(rule(Count::Exactly(3)).total(&schematic), 0);
+    }
+
+    #[test]
+    fn test_no_numbers() {
+        // the `*` touches no number, so it is a gear with an empty ratio
+        let schematic = Day03::parse("*..\n..5").unwrap();
+        for reducer in [Reducer::Product, Reducer::Sum, Reducer::Max] {
+            assert_eq!(reducer.reduce([]), 0);
+            let rule = GearRule {
+                symbol: '*',
+                count: Count::AtLeast(0),
+                reducer,
+            };
+            assert_eq!(rule.gears(&schematic).count(), 1);
+            assert_eq!(rule.total(&schematic), 0);
+        }
+    }
+
+    #[test]
+    fn test_reducers() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let rule = |reducer| GearRule {
+            reducer,
+            ..GearRule::PUZZLE
+        };
+        assert_eq!(rule(Reducer::Sum).total(&schematic), 467 + 35 + 755 + 598);
+        assert_eq!(rule(Reducer::Max).total(&schematic), 467 + 755);
+    }
+
+    #[test]
+    fn test_saturation() {
+        let big = [u32::MAX; 3];
+        assert_eq!(Reducer::Product.reduce(big), u64::MAX);
+        assert_eq!(Reducer::Sum.reduce(big), 3 * u64::from(u32::MAX));
+        assert_eq!(Reducer::Max.reduce(big), u64::from(u32::MAX));
+
+        // the `*` cells between the numbers each touch four 9 digit numbers
+        let row = "999999999.".repeat(3);
+        let input = format!("{row}\n{}\n{row}", "*".repeat(30));
+        let schematic = Day03::parse(&input).unwrap();
+        let rule = GearRule {
+            symbol: '*',
+            count: Count::AtLeast(3),
+            reducer: Reducer::Product,
+        };
+        assert_eq!(rule.total(&schematic), u64::MAX);
+    }
+
+    #[test]
+    fn test_other_symbols() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let rule = GearRule {
+            symbol: '+',
+            count: Count::Exactly(1),
+            reducer: Reducer::Max,
+        };
+        assert_eq!(rule.total(&schematic), 592);
+    }
+}
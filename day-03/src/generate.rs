@@ -0,0 +1,75 @@
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// Deterministic pseudo-random schematic for benchmarks, roughly as dense
+/// as the puzzle input. Time the solver on it with
+/// `aoc schematic generate | aoc bench --day 3 --input -`.
+pub fn generate(width: usize, height: usize, seed: u64) -> String {
+    let mut state = seed.max(1);
+    let mut next = move |n: u64| {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % n
+    };
+
+    let mut out = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        let mut row = Vec::with_capacity(width);
+        while row.len() < width {
+            match next(100) {
+                0..=5 => row.push(SYMBOLS[next(SYMBOLS.len() as u64) as usize]),
+                6..=13 => {
+                    let digits = (1 + next(3) as usize).min(width - row.len());
+                    row.push(b'1' + next(9) as u8);
+                    for _ in 1..digits {
+                        row.push(b'0' + next(10) as u8);
+                    }
+                    if row.len() < width {
+                        row.push(b'.');
+                    }
+                }
+                _ => row.push(b'.'),
+            }
+        }
+        out.push_str(std::str::from_utf8(&row).expect("Schematic is ASCII"));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::part1, part2::part2, Day03};
+    use aoc_common::Solution;
+    use std::time::Instant;
+
+    #[test]
+    fn test_generate() {
+        let result = generate(50, 20, 7);
+        assert_eq!(result, generate(50, 20, 7));
+        assert_eq!(result.lines().count(), 20);
+        assert!(result.lines().all(|line| line.len() == 50));
+    }
+
+    /// Times the solver on the 1000×1000 schematic printed by
+    /// `aoc schematic generate`, run with
+    /// `cargo test --release -p day-03 -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "timing, run in release"]
+    fn bench_large() {
+        let input = generate(1000, 1000, 1);
+        let start = Instant::now();
+        let schematic = Day03::parse(&input).unwrap();
+        let parsed = start.elapsed();
+        let (sum, part1_done) = (part1(&schematic), start.elapsed());
+        let (ratios, total) = (part2(&schematic), start.elapsed());
+        println!(
+            "parse {parsed:.2?}, part 1 {:.2?}, part 2 {:.2?}",
+            part1_done - parsed,
+            total - part1_done
+        );
+        assert_eq!((sum, ratios), (6041937, 29671718));
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use aoc_common::geometry::Point;
+
+use crate::Schematic;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberNode {
+    pub value: u32,
+    pub coords: Point,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolNode {
+    pub char: char,
+    pub coords: Point,
+}
+
+/// Bipartite graph linking every number to the symbols it touches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub numbers: Vec<NumberNode>,
+    pub symbols: Vec<SymbolNode>,
+    /// Pairs of indices into `numbers` and `symbols`.
+    pub edges: Vec<(usize, usize)>,
+}
+
+pub fn graph(schematic: &Schematic) -> Graph {
+    let symbol_at = schematic
+        .symbols
+        .iter()
+        .enumerate()
+        .map(|(i, symbol)| (symbol.coords, i))
+        .collect::<HashMap<Point, usize>>();
+    let mut edges = Vec::new();
+    for (i, sn) in schematic.serial_numbers.iter().enumerate() {
+        for symbol in schematic.adjacent_symbols(sn) {
+            edges.push((i, symbol_at[&symbol.coords]));
+        }
+    }
+    Graph {
+        numbers: schematic
+            .serial_numbers
+            .iter()
+            .map(|sn| NumberNode {
+                value: sn.value,
+                coords: sn.coords(),
+            })
+            .collect(),
+        symbols: schematic
+            .symbols
+            .iter()
+            .map(|symbol| SymbolNode {
+                char: symbol.char,
+                coords: symbol.coords,
+            })
+            .collect(),
+        edges,
+    }
+}
+
+impl Graph {
+    /// Number of symbols each number touches.
+    pub fn number_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.numbers.len()];
+        for (n, _) in &self.edges {
+            degrees[*n] += 1;
+        }
+        degrees
+    }
+
+    /// Number of numbers each symbol touches.
+    pub fn symbol_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.symbols.len()];
+        for (_, s) in &self.edges {
+            degrees[*s] += 1;
+        }
+        degrees
+    }
+
+    /// Graphviz rendering, numbers as boxes and symbols as circles.
+    pub fn dot(&self) -> String {
+        let mut out = String::from("graph schematic {\n");
+        for (i, n) in self.numbers.iter().enumerate() {
+            out.push_str(&format!(
+                "  n{i} [label=\"{} ({}, {})\", shape=box];\n",
+                n.value, n.coords.x, n.coords.y
+            ));
+        }
+        for (i, s) in self.symbols.iter().enumerate() {
+            let char = match s.char {
+                '"' | '\\' => format!("\\{}", s.char),
+                c => c.to_string(),
+            };
+            out.push_str(&format!(
+                "  s{i} [label=\"{char} ({}, {})\", shape=circle];\n",
+                s.coords.x, s.coords.y
+            ));
+        }
+        for (n, s) in &self.edges {
+            out.push_str(&format!("  n{n} -- s{s};\n"));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_graph() {
+        let result = graph(&Day03::parse(EXAMPLE).unwrap());
+        assert_eq!(result.numbers.len(), 10);
+        let expected = vec![
+            (0, 0),
+            (2, 0),
+            (3, 1),
+            (4, 2),
+            (6, 3),
+            (7, 5),
+            (8, 4),
+            (9, 5),
+        ];
+        assert_eq!(result.edges, expected);
+        // 114 and 58 touch no symbol
+        assert_eq!(result.number_degrees(), vec![1, 0, 1, 1, 1, 0, 1, 1, 1, 1]);
+        assert_eq!(result.symbol_degrees(), vec![2, 1, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let result = graph(&Day03::parse("1*").unwrap()).dot();
+        let expected = "graph schematic {
+  n0 [label=\"1 (0, 0)\", shape=box];
+  s0 [label=\"* (1, 0)\", shape=circle];
+  n0 -- s0;
+}
+";
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,359 @@
+use aoc_common::{geometry::Point, parse::lines, Grid, Result, Solution};
+use neighbourhood::Neighbourhood;
+use std::{iter::once, ops::Range};
+
+pub mod gears;
+pub mod generate;
+pub mod graph;
+pub mod neighbourhood;
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerialNumber {
+    pub value: u32,
+    pub row: i64,
+    /// Columns covered by the digits of the number.
+    pub span: Range<i64>,
+}
+
+impl SerialNumber {
+    /// Position of the first digit.
+    pub fn coords(&self) -> Point {
+        Point::new(self.span.start, self.row)
+    }
+
+    /// Points covered by the digits of the number.
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        self.span.clone().map(|x| Point::new(x, self.row))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub coords: Point,
+    pub char: char,
+}
+
+/// Any character which is neither a digit nor `.`.
+pub fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// Predicate matching the symbols in `chars`, e.g. `one_of("*#+")`.
+pub fn one_of(chars: &str) -> impl Fn(char) -> bool + '_ {
+    move |c| chars.contains(c)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    /// Numbers in reading order.
+    pub serial_numbers: Vec<SerialNumber>,
+    /// Symbols in reading order.
+    pub symbols: Vec<Symbol>,
+    pub neighbourhood: Neighbourhood,
+    /// Indices into `serial_numbers` of the numbers of each row.
+    numbers_by_row: Vec<Range<usize>>,
+    /// Indices into `symbols` of the symbols of each row.
+    symbols_by_row: Vec<Range<usize>>,
+}
+
+impl Schematic {
+    pub fn symbols_matching<'a>(
+        &'a self,
+        predicate: impl Fn(char) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a Symbol> {
+        self.symbols.iter().filter(move |s| predicate(s.char))
+    }
+
+    /// Answers adjacency queries with `neighbourhood` instead of the eight
+    /// surrounding cells.
+    pub fn with_neighbourhood(self, neighbourhood: Neighbourhood) -> Self {
+        Schematic {
+            neighbourhood,
+            ..self
+        }
+    }
+
+    /// Rows within reach of `row` together with the columns reached on
+    /// either side.
+    fn rows_around(&self, row: i64) -> impl Iterator<Item = (usize, i64)> + '_ {
+        let height = self.neighbourhood.height();
+        let rows = (row - height).max(0)..(row + height + 1).min(self.grid.height() as i64);
+        rows.filter_map(move |y| Some((y as usize, self.neighbourhood.reach(y - row)?)))
+    }
+
+    /// Symbols touching the number in reading order.
+    pub fn adjacent_symbols(&self, sn: &SerialNumber) -> Vec<&Symbol> {
+        let mut adjacent = Vec::new();
+        for (row, reach) in self.rows_around(sn.row) {
+            let (left, right) = (sn.span.start - reach, sn.span.end - 1 + reach);
+            let symbols = &self.symbols[self.symbols_by_row[row].clone()];
+            let first = symbols.partition_point(|s| s.coords.x < left);
+            adjacent.extend(symbols[first..].iter().take_while(|s| s.coords.x <= right));
+        }
+        adjacent
+    }
+
+    /// Numbers touching the symbol in reading order.
+    pub fn adjacent_numbers(&self, symbol: &Symbol) -> Vec<&SerialNumber> {
+        let x = symbol.coords.x;
+        let mut adjacent = Vec::new();
+        for (row, reach) in self.rows_around(symbol.coords.y) {
+            let numbers = &self.serial_numbers[self.numbers_by_row[row].clone()];
+            let first = numbers.partition_point(|sn| sn.span.end <= x - reach);
+            adjacent.extend(
+                numbers[first..]
+                    .iter()
+                    .take_while(|sn| sn.span.start <= x + reach),
+            );
+        }
+        adjacent
+    }
+
+    /// Numbers touching at least one symbol matching `predicate`.
+    pub fn part_numbers<'a>(
+        &'a self,
+        predicate: impl Fn(char) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a SerialNumber> {
+        self.serial_numbers.iter().filter(move |sn| {
+            self.adjacent_symbols(sn)
+                .iter()
+                .any(|symbol| predicate(symbol.char))
+        })
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_schematic(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn parse_schematic(input: &str) -> Result<Schematic> {
+    let grid = Grid::parse_with(input, |c| match c.is_ascii_graphic() {
+        true => Ok(c),
+        false => Err(format!("expected a printable ASCII character, found {c:?}")),
+    })?;
+    let mut serial_numbers: Vec<SerialNumber> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+    let mut numbers_by_row = Vec::new();
+    let mut symbols_by_row = Vec::new();
+    for (y, line) in lines(input).enumerate() {
+        let (first_number, first_symbol) = (serial_numbers.len(), symbols.len());
+        // the grid is ASCII so byte offsets are columns
+        let mut start: Option<usize> = None;
+        // a trailing `.` flushes a number touching the end of the line
+        let cells = line.text.bytes().chain(once(b'.')).map(char::from);
+        for (x, c) in cells.enumerate() {
+            if c.is_ascii_digit() {
+                start.get_or_insert(x);
+                continue;
+            }
+            if let Some(start) = start.take() {
+                serial_numbers.push(SerialNumber {
+                    value: line.parse(&line.text[start..x], "number")?,
+                    row: y as i64,
+                    span: start as i64..x as i64,
+                });
+            }
+            if is_symbol(c) {
+                let coords = Point::new(x as i64, y as i64);
+                symbols.push(Symbol { coords, char: c });
+            }
+        }
+        numbers_by_row.push(first_number..serial_numbers.len());
+        symbols_by_row.push(first_symbol..symbols.len());
+    }
+
+    Ok(Schematic {
+        grid,
+        serial_numbers,
+        symbols,
+        neighbourhood: Neighbourhood::Eight,
+        numbers_by_row,
+        symbols_by_row,
+    })
+}
+
+/// Example from the puzzle description, shared by the tests.
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schematic() {
+        let number = |value, row, span| SerialNumber { value, row, span };
+        let expected = vec![
+            number(467, 0, 0..3),
+            number(114, 0, 5..8),
+            number(35, 2, 2..4),
+            number(633, 2, 6..9),
+            number(617, 4, 0..3),
+            number(58, 5, 7..9),
+            number(592, 6, 2..5),
+            number(755, 7, 6..9),
+            number(664, 9, 1..4),
+            number(598, 9, 5..8),
+        ];
+        let result = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(result.serial_numbers, expected);
+        let symbol = |x, y, char| Symbol {
+            coords: Point::new(x, y),
+            char,
+        };
+        let symbols = vec![
+            symbol(3, 1, '*'),
+            symbol(6, 3, '#'),
+            symbol(3, 4, '*'),
+            symbol(5, 5, '+'),
+            symbol(3, 8, '$'),
+            symbol(5, 8, '*'),
+        ];
+        assert_eq!(result.symbols, symbols);
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let numbers = schematic
+            .adjacent_numbers(&schematic.symbols[0])
+            .iter()
+            .map(|sn| sn.value)
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![467, 35]);
+        let symbols = schematic.adjacent_symbols(&schematic.serial_numbers[3]);
+        assert_eq!(symbols, vec![&schematic.symbols[1]]);
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let values = |predicate: &dyn Fn(char) -> bool| {
+            schematic
+                .part_numbers(predicate)
+                .map(|sn| sn.value)
+                .collect::<Vec<_>>()
+        };
+        let expected = vec![467, 35, 633, 617, 592, 755, 664, 598];
+        assert_eq!(values(&is_symbol), expected);
+        assert_eq!(values(&one_of("#+")), vec![633, 592]);
+    }
+
+    #[test]
+    fn test_adjacency_matches_neighbours() {
+        let generated = generate::generate(60, 40, 3);
+        let neighbourhoods = [
+            Neighbourhood::Four,
+            Neighbourhood::Eight,
+            Neighbourhood::Radius(3),
+        ];
+        for neighbourhood in neighbourhoods {
+            let schematic = Day03::parse(&generated)
+                .unwrap()
+                .with_neighbourhood(neighbourhood);
+            let touches = |sn: &SerialNumber, symbol: &Symbol| {
+                sn.cells()
+                    .any(|p| neighbourhood.contains(symbol.coords - p))
+            };
+            for sn in &schematic.serial_numbers {
+                let expected = schematic
+                    .symbols
+                    .iter()
+                    .filter(|symbol| touches(sn, symbol))
+                    .collect::<Vec<_>>();
+                assert_eq!(schematic.adjacent_symbols(sn), expected);
+            }
+            for symbol in &schematic.symbols {
+                let expected = schematic
+                    .serial_numbers
+                    .iter()
+                    .filter(|sn| touches(sn, symbol))
+                    .collect::<Vec<_>>();
+                assert_eq!(schematic.adjacent_numbers(symbol), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_edges() {
+        // numbers end at the right edge and never continue on the next line
+        let schematic = Day03::parse("..#12\n34...\n*...9").unwrap();
+        let numbers = schematic
+            .serial_numbers
+            .iter()
+            .map(|sn| (sn.value, sn.row, sn.span.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![(12, 0, 3..5), (34, 1, 0..2), (9, 2, 4..5)]);
+        let symbols = schematic.symbols.iter().map(|s| s.coords);
+        assert_eq!(
+            symbols.collect::<Vec<_>>(),
+            vec![Point::new(2, 0), Point::new(0, 2)]
+        );
+        let parts = schematic.part_numbers(is_symbol).map(|sn| sn.value);
+        assert_eq!(parts.collect::<Vec<_>>(), vec![12, 34]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            (
+                "..\n.é",
+                "expected a printable ASCII character, found 'é'",
+                (2, 2, 1),
+            ),
+            (
+                "12.\n. .",
+                "expected a printable ASCII character, found ' '",
+                (2, 2, 1),
+            ),
+            (
+                "12.\n.\t.",
+                "expected a printable ASCII character, found '\\t'",
+                (2, 2, 1),
+            ),
+            (
+                "*4294967296",
+                "could not parse number `4294967296`",
+                (1, 2, 10),
+            ),
+        ];
+        for (input, message, expected) in cases {
+            let err = Day03::parse(input).unwrap_err();
+            assert_eq!(err.message, message, "{input:?}");
+            let location = err.location.unwrap();
+            let result = (location.line, location.column, location.width);
+            assert_eq!(result, expected, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use aoc_common::geometry::Vector;
+
+/// Cells around a number or symbol which count as touching it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Orthogonal neighbours only.
+    Four,
+    /// Orthogonal and diagonal neighbours, as in the puzzle.
+    Eight,
+    /// Every cell within this Chebyshev distance.
+    Radius(u32),
+}
+
+impl Neighbourhood {
+    pub fn contains(self, offset: Vector) -> bool {
+        match self {
+            Neighbourhood::Four => offset.manhattan() == 1,
+            Neighbourhood::Eight => offset.chebyshev() == 1,
+            Neighbourhood::Radius(k) => (1..=u64::from(k)).contains(&offset.chebyshev()),
+        }
+    }
+
+    /// Furthest row reached above and below.
+    pub fn height(self) -> i64 {
+        match self {
+            Neighbourhood::Four | Neighbourhood::Eight => 1,
+            Neighbourhood::Radius(k) => k.into(),
+        }
+    }
+
+    /// Furthest column reached on either side `dy` rows away.
+    pub fn reach(self, dy: i64) -> Option<i64> {
+        match (self, dy.unsigned_abs()) {
+            (Neighbourhood::Four, 0) => Some(1),
+            (Neighbourhood::Four, 1) => Some(0),
+            (Neighbourhood::Eight, 0..=1) => Some(1),
+            (Neighbourhood::Radius(k), dy) if dy <= k.into() => Some(k.into()),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Neighbourhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "4" => Ok(Neighbourhood::Four),
+            "8" => Ok(Neighbourhood::Eight),
+            _ => match s.strip_prefix("radius=").map(str::parse) {
+                Some(Ok(k)) if k > 0 => Ok(Neighbourhood::Radius(k)),
+                _ => Err(format!(
+                    "unknown neighbourhood `{s}`, expected 4, 8 or radius=K"
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gears::GearRule, is_symbol, Day03, Schematic, EXAMPLE};
+    use aoc_common::Solution;
+
+    fn schematic(neighbourhood: Neighbourhood) -> Schematic {
+        Day03::parse(EXAMPLE)
+            .unwrap()
+            .with_neighbourhood(neighbourhood)
+    }
+
+    fn part_numbers(neighbourhood: Neighbourhood) -> Vec<u32> {
+        schematic(neighbourhood)
+            .part_numbers(is_symbol)
+            .map(|sn| sn.value)
+            .collect()
+    }
+
+    fn around_first_symbol(neighbourhood: Neighbourhood) -> Vec<u32> {
+        let schematic = schematic(neighbourhood);
+        schematic
+            .adjacent_numbers(&schematic.symbols[0])
+            .iter()
+            .map(|sn| sn.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let cases = vec![
+            ("4", Ok(Neighbourhood::Four)),
+            ("8", Ok(Neighbourhood::Eight)),
+            ("radius=3", Ok(Neighbourhood::Radius(3))),
+            ("radius=0", Err(())),
+            ("6", Err(())),
+        ];
+        for (s, expected) in cases {
+            let result = s.parse::<Neighbourhood>().map_err(|_| ());
+            assert_eq!(result, expected, "{s}");
+        }
+    }
+
+    #[test]
+    fn test_four() {
+        // diagonal contacts such as 467 with `*` or 592 with `+` no longer count
+        let expected = vec![35, 633, 617, 664, 598];
+        assert_eq!(part_numbers(Neighbourhood::Four), expected);
+        assert_eq!(around_first_symbol(Neighbourhood::Four), vec![35]);
+        let total = GearRule::PUZZLE.total(&schematic(Neighbourhood::Four));
+        assert_eq!(total, 0);
+    }
+
+    #[test]
+    fn test_eight() {
+        let expected = vec![467, 35, 633, 617, 592, 755, 664, 598];
+        assert_eq!(part_numbers(Neighbourhood::Eight), expected);
+        assert_eq!(around_first_symbol(Neighbourhood::Eight), vec![467, 35]);
+        let total = GearRule::PUZZLE.total(&schematic(Neighbourhood::Eight));
+        assert_eq!(total, 467835);
+    }
+
+    #[test]
+    fn test_radius() {
+        assert_eq!(
+            part_numbers(Neighbourhood::Radius(1)),
+            part_numbers(Neighbourhood::Eight)
+        );
+        // 114 and 58 are two columns away from `*` and `+`
+        let expected = vec![467, 114, 35, 633, 617, 58, 592, 755, 664, 598];
+        assert_eq!(part_numbers(Neighbourhood::Radius(2)), expected);
+        let expected = vec![467, 114, 35];
+        assert_eq!(around_first_symbol(Neighbourhood::Radius(2)), expected);
+    }
+}
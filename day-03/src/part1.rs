@@ -0,0 +1,22 @@
+use crate::{is_symbol, Schematic};
+
+pub fn part1(schematic: &Schematic) -> u64 {
+    schematic
+        .part_numbers(is_symbol)
+        .map(|sn| u64::from(sn.value))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 4361;
+        let result = part1(&Day03::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{gears::GearRule, Schematic};
+
+pub fn part2(schematic: &Schematic) -> u64 {
+    GearRule::PUZZLE.total(schematic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 467835;
+        let result = part2(&Day03::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+}
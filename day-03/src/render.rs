@@ -0,0 +1,161 @@
+use aoc_common::Grid;
+
+use crate::{gears::GearRule, is_symbol, Schematic};
+
+/// What a cell of the schematic is part of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    /// Digit of a number next to a symbol.
+    Part,
+    /// Digit of a number without any adjacent symbol.
+    Orphan,
+    /// Symbol qualifying as a gear.
+    Gear,
+    Symbol,
+    Empty,
+}
+
+impl Class {
+    fn ansi(self) -> &'static str {
+        match self {
+            Class::Part => "\x1b[32m",
+            Class::Orphan => "\x1b[31m",
+            Class::Gear => "\x1b[1;33m",
+            Class::Symbol => "\x1b[1m",
+            Class::Empty => "\x1b[2m",
+        }
+    }
+
+    fn css(self) -> &'static str {
+        match self {
+            Class::Part => "part",
+            Class::Orphan => "orphan",
+            Class::Gear => "gear",
+            Class::Symbol => "symbol",
+            Class::Empty => "empty",
+        }
+    }
+}
+
+pub fn classify(schematic: &Schematic, rule: &GearRule) -> Grid<Class> {
+    let mut classes = schematic.grid.map(|c| match is_symbol(*c) {
+        true => Class::Symbol,
+        false => Class::Empty,
+    });
+    for sn in &schematic.serial_numbers {
+        let class = match schematic.adjacent_symbols(sn).is_empty() {
+            true => Class::Orphan,
+            false => Class::Part,
+        };
+        for p in sn.cells() {
+            classes[p] = class;
+        }
+    }
+    for (gear, _) in rule.gears(schematic) {
+        classes[gear.coords] = Class::Gear;
+    }
+    classes
+}
+
+/// Runs of consecutive cells of the same class within a row.
+fn runs<'a>(row: &'a [char], classes: &'a [Class]) -> impl Iterator<Item = (Class, String)> + 'a {
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        let class = *classes.get(start)?;
+        let len = classes[start..].iter().take_while(|c| **c == class).count();
+        let text = row[start..start + len].iter().collect();
+        start += len;
+        Some((class, text))
+    })
+}
+
+/// The schematic with ANSI colours: green parts, red orphans, yellow gears.
+pub fn ansi(schematic: &Schematic, rule: &GearRule) -> String {
+    let classes = classify(schematic, rule);
+    let mut out = String::new();
+    for (row, classes) in schematic.grid.rows().zip(classes.rows()) {
+        for (class, text) in runs(row, classes) {
+            out.push_str(&format!("{}{text}\x1b[0m", class.ansi()));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A standalone HTML page showing the schematic with the colours of `ansi`.
+pub fn html(schematic: &Schematic, rule: &GearRule) -> String {
+    let classes = classify(schematic, rule);
+    let mut out = String::from(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Engine schematic</title>
+<style>
+body { background: #111; color: #ccc; }
+.part { color: #4c4; }
+.orphan { color: #e44; }
+.gear { color: #fd0; font-weight: bold; }
+.symbol { color: #fff; font-weight: bold; }
+.empty { color: #555; }
+</style>
+</head>
+<body>
+<pre>
+",
+    );
+    for (row, classes) in schematic.grid.rows().zip(classes.rows()) {
+        for (class, text) in runs(row, classes) {
+            out.push_str(&format!(
+                "<span class=\"{}\">{}</span>",
+                class.css(),
+                escape(&text)
+            ));
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, EXAMPLE};
+    use aoc_common::{geometry::Point, Solution};
+
+    #[test]
+    fn test_classify() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let classes = classify(&schematic, &GearRule::PUZZLE);
+        assert_eq!(classes[Point::new(0, 0)], Class::Part);
+        assert_eq!(classes[Point::new(5, 0)], Class::Orphan);
+        assert_eq!(classes[Point::new(3, 1)], Class::Gear);
+        assert_eq!(classes[Point::new(6, 3)], Class::Symbol);
+        assert_eq!(classes[Point::new(4, 2)], Class::Empty);
+    }
+
+    #[test]
+    fn test_ansi() {
+        let schematic = Day03::parse("1.\n.#").unwrap();
+        let expected = "\x1b[32m1\x1b[0m\x1b[2m.\x1b[0m\n\x1b[2m.\x1b[0m\x1b[1m#\x1b[0m\n";
+        let result = ansi(&schematic, &GearRule::PUZZLE);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_html() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let result = html(&schematic, &GearRule::PUZZLE);
+        assert!(result.contains("<span class=\"orphan\">114</span>"));
+        assert!(result.contains("<span class=\"part\">467</span>"));
+        let result = html(&Day03::parse("1&").unwrap(), &GearRule::PUZZLE);
+        assert!(result.contains("<span class=\"symbol\">&amp;</span>"));
+    }
+}
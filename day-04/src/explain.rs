@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::{scoring::ScoringRule, Card};
+
+/// Per-card breakdown of the matched numbers, points and final copies.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CardReport {
+    pub id: u32,
+    pub matched: Vec<u32>,
+    pub points: u64,
+    pub copies: u64,
+}
+
+impl fmt::Display for CardReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let matched = self
+            .matched
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(
+            f,
+            "Card {:>3}: {} matches [{matched}], {} points, {} copies",
+            self.id,
+            self.matched.len(),
+            self.points,
+            self.copies
+        )
+    }
+}
+
+pub fn explain(cards: &[Card], rule: &dyn ScoringRule) -> Vec<CardReport> {
+    cards
+        .iter()
+        .zip(rule.copies(cards))
+        .map(|(card, copies)| CardReport {
+            id: card.id,
+            matched: card.matched(),
+            points: rule.points(card.matches()),
+            copies,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{scoring::Doubling, Day04, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_explain() {
+        let result = explain(&Day04::parse(EXAMPLE).unwrap(), &Doubling);
+        let copies = result.iter().map(|r| r.copies).collect::<Vec<_>>();
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(
+            result[0].to_string(),
+            "Card   1: 4 matches [83 86 17 48], 8 points, 1 copies"
+        );
+    }
+}
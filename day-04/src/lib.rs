@@ -0,0 +1,188 @@
+use aoc_common::{
+    parse::{lines, Line},
+    Result, Solution,
+};
+use scoring::{Doubling, ScoringRule};
+
+pub mod explain;
+pub mod part1;
+pub mod part2;
+pub mod scoring;
+pub mod validate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub mine: Vec<u32>,
+}
+
+impl Card {
+    /// My numbers which are also winning numbers, each counted once.
+    pub fn matched(&self) -> Vec<u32> {
+        let mut matched = Vec::new();
+        for num in &self.mine {
+            if self.winning.contains(num) && !matched.contains(num) {
+                matched.push(*num);
+            }
+        }
+        matched
+    }
+
+    pub fn matches(&self) -> usize {
+        self.matched().len()
+    }
+
+    /// Points under the puzzle's doubling rule.
+    pub fn points(&self) -> u64 {
+        Doubling.points(self.matches())
+    }
+}
+
+/// Where the id and numbers of a card are on its line, used to point at the
+/// issues found by validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardSpans<'a> {
+    pub line: Line<'a>,
+    pub id: &'a str,
+    pub winning: Vec<&'a str>,
+    pub mine: Vec<&'a str>,
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let cards = parse_cards(input)?;
+        Ok(cards.into_iter().map(|(card, _)| card).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        validate::issues(input)
+            .into_iter()
+            .map(|(_, issue)| issue.to_string())
+            .collect()
+    }
+}
+
+/// Cards sorted by id, wins go to the next ids whatever the input order.
+pub fn parse_cards(input: &str) -> Result<Vec<(Card, CardSpans<'_>)>> {
+    let mut cards = lines(input)
+        .map(|line| parse_card(&line))
+        .collect::<Result<Vec<_>>>()?;
+    cards.sort_by_key(|(card, _)| card.id);
+    Ok(cards)
+}
+
+fn parse_card<'a>(line: &Line<'a>) -> Result<(Card, CardSpans<'a>)> {
+    let (header, body) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.error(line.text, "expected `Card ID: NUMBERS | NUMBERS`"))?;
+
+    let card_id = header
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| line.error(header, "no card id found"))?;
+    let id = line.parse::<u32>(card_id, "card id")?;
+
+    let (winning, mine) = body
+        .split_once('|')
+        .ok_or_else(|| line.error(body, "expected `NUMBERS | NUMBERS`"))?;
+
+    let card = Card {
+        id,
+        winning: line.numbers(winning)?,
+        mine: line.numbers(mine)?,
+    };
+    let spans = CardSpans {
+        line: *line,
+        id: card_id,
+        winning: winning.split_whitespace().collect(),
+        mine: mine.split_whitespace().collect(),
+    };
+    Ok((card, spans))
+}
+
+/// Example from the puzzle description, shared by the tests.
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_card() {
+        let input = Line {
+            number: 1,
+            text: "Card  1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+        };
+        let expected = Card {
+            id: 1,
+            winning: vec![41, 48, 83, 86, 17],
+            mine: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        };
+        let (result, spans) = parse_card(&input).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(spans.id, "1");
+        assert_eq!(spans.mine[2], "6");
+    }
+
+    #[test]
+    fn test_parse_card_error() {
+        let input = Line {
+            number: 7,
+            text: "Card 7: 41 48 83 86 17 83 86  6 31",
+        };
+        let location = parse_card(&input).unwrap_err().location.unwrap();
+        assert_eq!(location.line, 7);
+        assert_eq!(location.column, 8);
+    }
+
+    #[test]
+    fn test_card_points() {
+        let cases = vec![
+            (
+                "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+                vec![83, 86, 17, 48],
+                8,
+            ),
+            (
+                "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+                vec![21, 1],
+                2,
+            ),
+            (
+                "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+                vec![],
+                0,
+            ),
+            ("Card 9: 1 2 | 2 2 1", vec![2, 1], 2),
+        ];
+        for (text, matched, points) in cases {
+            let (card, _) = parse_card(&Line { number: 1, text }).unwrap();
+            assert_eq!(card.matched(), matched);
+            assert_eq!(card.points(), points);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    scoring::{total_points, Doubling},
+    Card,
+};
+
+pub fn part1(cards: &[Card]) -> u64 {
+    total_points(cards, &Doubling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day04, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 13;
+        let result = part1(&Day04::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+}
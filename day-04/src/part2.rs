@@ -0,0 +1,55 @@
+use crate::{
+    scoring::{total_copies, Doubling},
+    Card,
+};
+
+pub fn part2(cards: &[Card]) -> u64 {
+    total_copies(cards, &Doubling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day04, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let expected = 30;
+        let result = part2(&Day04::parse(EXAMPLE).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_stress() {
+        // every card but the last wins a copy of the next one, so card n ends
+        // up with n copies
+        let n = 10_000;
+        let input = (1..=n)
+            .map(|id| match id {
+                i if i == n => format!("Card {id}: 1 2 3 | 7 8 9"),
+                _ => format!("Card {id}: 1 2 3 | 1 8 9"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let expected = n * (n + 1) / 2;
+        let result = part2(&Day04::parse(&input).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shuffled() {
+        let input = "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19";
+        let cards = Day04::parse(input).unwrap();
+        assert_eq!(
+            cards.iter().map(|card| card.id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(part2(&cards), 30);
+    }
+}
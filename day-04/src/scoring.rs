@@ -0,0 +1,202 @@
+use crate::Card;
+
+/// How a card is scored and which copies it wins.
+pub trait ScoringRule {
+    /// Points of a card with `matches` matching numbers, saturating at
+    /// `u64::MAX`.
+    fn points(&self, matches: usize) -> u64;
+
+    /// Final number of copies of each card, saturating at `u64::MAX`. Wins go
+    /// to the next cards by default.
+    fn copies(&self, cards: &[Card]) -> Vec<u64> {
+        win_next(cards, u64::MAX)
+    }
+}
+
+/// Puzzle rules: points double with each match, wins go to the next cards.
+pub struct Doubling;
+
+/// One point per match.
+pub struct Linear;
+
+/// Points follow the Fibonacci sequence 1, 2, 3, 5, 8, ...
+pub struct Fibonacci;
+
+/// Doubling points, but wins go to the previous cards instead.
+pub struct WinPrevious;
+
+/// Doubling points, but no card ends up with more than `cap` copies.
+pub struct CappedCopies {
+    pub cap: u64,
+}
+
+impl ScoringRule for Doubling {
+    fn points(&self, matches: usize) -> u64 {
+        match matches {
+            0 => 0,
+            c => u32::try_from(c - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl ScoringRule for Linear {
+    fn points(&self, matches: usize) -> u64 {
+        matches as u64
+    }
+}
+
+impl ScoringRule for Fibonacci {
+    fn points(&self, matches: usize) -> u64 {
+        let (mut a, mut b) = (0u64, 1u64);
+        for _ in 0..matches {
+            (a, b) = (b, a.saturating_add(b));
+            if a == u64::MAX {
+                break;
+            }
+        }
+        match matches {
+            0 => 0,
+            _ => b,
+        }
+    }
+}
+
+impl ScoringRule for WinPrevious {
+    fn points(&self, matches: usize) -> u64 {
+        Doubling.points(matches)
+    }
+
+    /// Copies are propagated backwards, from the last card to the first.
+    fn copies(&self, cards: &[Card]) -> Vec<u64> {
+        let mut copies = vec![1u64; cards.len()];
+        for (i, card) in cards.iter().enumerate().rev() {
+            for j in i.saturating_sub(card.matches())..i {
+                copies[j] = copies[j].saturating_add(copies[i]);
+            }
+        }
+        copies
+    }
+}
+
+impl ScoringRule for CappedCopies {
+    fn points(&self, matches: usize) -> u64 {
+        Doubling.points(matches)
+    }
+
+    fn copies(&self, cards: &[Card]) -> Vec<u64> {
+        win_next(cards, self.cap)
+    }
+}
+
+/// Propagates copy counts forward in card order: every copy of a card wins
+/// one more copy of each of the next `matches` cards, up to `cap` copies.
+/// Wins past the last card are dropped.
+fn win_next(cards: &[Card], cap: u64) -> Vec<u64> {
+    let mut copies = vec![1u64.min(cap); cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let last = (i + card.matches()).min(cards.len() - 1);
+        for j in i + 1..=last {
+            copies[j] = copies[j].saturating_add(copies[i]).min(cap);
+        }
+    }
+    copies
+}
+
+/// Rule from its command line name: `doubling`, `linear`, `fibonacci`,
+/// `win-previous` or `capped=N`.
+pub fn parse_rule(name: &str) -> Result<Box<dyn ScoringRule>, String> {
+    match name {
+        "doubling" => Ok(Box::new(Doubling)),
+        "linear" => Ok(Box::new(Linear)),
+        "fibonacci" => Ok(Box::new(Fibonacci)),
+        "win-previous" => Ok(Box::new(WinPrevious)),
+        _ => match name.strip_prefix("capped=").map(str::parse) {
+            Some(Ok(cap)) => Ok(Box::new(CappedCopies { cap })),
+            _ => Err(format!("unknown scoring rule `{name}`")),
+        },
+    }
+}
+
+pub fn total_points(cards: &[Card], rule: &dyn ScoringRule) -> u64 {
+    cards.iter().fold(0, |total, card| {
+        total.saturating_add(rule.points(card.matches()))
+    })
+}
+
+pub fn total_copies(cards: &[Card], rule: &dyn ScoringRule) -> u64 {
+    rule.copies(cards)
+        .iter()
+        .fold(0, |total, copies| total.saturating_add(*copies))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day04, EXAMPLE};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_doubling() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(total_points(&cards, &Doubling), 13);
+        assert_eq!(Doubling.copies(&cards), vec![1, 2, 4, 8, 14, 1]);
+    }
+
+    #[test]
+    fn test_linear() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(total_points(&cards, &Linear), 9);
+        assert_eq!(total_copies(&cards, &Linear), 30);
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let points = (0..6).map(|m| Fibonacci.points(m)).collect::<Vec<_>>();
+        assert_eq!(points, vec![0, 1, 2, 3, 5, 8]);
+        assert_eq!(total_points(&cards, &Fibonacci), 10);
+        assert_eq!(total_copies(&cards, &Fibonacci), 30);
+    }
+
+    #[test]
+    fn test_points_saturate() {
+        assert_eq!(Doubling.points(64), 1 << 63);
+        assert_eq!(Doubling.points(65), u64::MAX);
+        assert_eq!(Fibonacci.points(92), 12200160415121876738);
+        assert_eq!(Fibonacci.points(93), u64::MAX);
+        assert_eq!(Fibonacci.points(usize::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_win_previous() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(total_points(&cards, &WinPrevious), 13);
+        assert_eq!(WinPrevious.copies(&cards), vec![6, 3, 2, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_capped_copies() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let rule = CappedCopies { cap: 2 };
+        assert_eq!(total_points(&cards, &rule), 13);
+        assert_eq!(rule.copies(&cards), vec![1, 2, 2, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        for name in [
+            "doubling",
+            "linear",
+            "fibonacci",
+            "win-previous",
+            "capped=3",
+        ] {
+            assert!(parse_rule(name).is_ok(), "{name}");
+        }
+        assert!(parse_rule("capped=x").is_err());
+        assert!(parse_rule("quadratic").is_err());
+    }
+}
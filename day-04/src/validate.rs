@@ -0,0 +1,255 @@
+use std::fmt;
+
+use aoc_common::Result;
+
+use crate::{Card, CardSpans};
+
+/// What to do with the issues found in a card table. Cards are scored the
+/// same way whenever they are scored: duplicate numbers count once, wins
+/// stop at the last card and copies follow the card ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Report the issues and clamp the scoring as above.
+    Clamp,
+    /// Fail on the first issue, pointing at the offending card.
+    Error,
+    /// Carry on without reporting anything.
+    Ignore,
+}
+
+impl std::str::FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "clamp" => Ok(Policy::Clamp),
+            "error" => Ok(Policy::Error),
+            "ignore" => Ok(Policy::Ignore),
+            _ => Err(format!("unknown policy `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Winning,
+    Mine,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    DuplicateNumber {
+        card: u32,
+        side: Side,
+        number: u32,
+        /// Index of the repeated number within its side.
+        index: usize,
+    },
+    IdGap {
+        expected: u32,
+        found: u32,
+    },
+    WinsPastEnd {
+        card: u32,
+        wins: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::DuplicateNumber {
+                card, side, number, ..
+            } => {
+                let side = match side {
+                    Side::Winning => "winning",
+                    Side::Mine => "own",
+                };
+                write!(f, "card {card}: duplicate {side} number {number}")
+            }
+            Issue::IdGap { expected, found } => {
+                write!(f, "card {found}: expected card {expected}")
+            }
+            Issue::WinsPastEnd {
+                card,
+                wins,
+                available,
+            } => write!(
+                f,
+                "card {card}: wins {wins} cards but only {available} follow"
+            ),
+        }
+    }
+}
+
+impl Issue {
+    /// The offending text within the line of the card.
+    fn span<'a>(&self, spans: &CardSpans<'a>) -> &'a str {
+        match self {
+            Issue::DuplicateNumber {
+                side: Side::Winning,
+                index,
+                ..
+            } => spans.winning[*index],
+            Issue::DuplicateNumber {
+                side: Side::Mine,
+                index,
+                ..
+            } => spans.mine[*index],
+            Issue::IdGap { .. } => spans.id,
+            Issue::WinsPastEnd { .. } => spans.line.text,
+        }
+    }
+}
+
+/// Issues of cards sorted by id, each with the index of the offending card.
+pub fn issues(cards: &[Card]) -> Vec<(usize, Issue)> {
+    let mut issues = Vec::new();
+    for (i, card) in cards.iter().enumerate() {
+        for (side, numbers) in [(Side::Winning, &card.winning), (Side::Mine, &card.mine)] {
+            for (j, number) in numbers.iter().enumerate() {
+                if numbers[..j].contains(number) {
+                    let issue = Issue::DuplicateNumber {
+                        card: card.id,
+                        side,
+                        number: *number,
+                        index: j,
+                    };
+                    issues.push((i, issue));
+                }
+            }
+        }
+
+        let expected = match i {
+            0 => 1,
+            _ => cards[i - 1].id + 1,
+        };
+        if card.id != expected {
+            let issue = Issue::IdGap {
+                expected,
+                found: card.id,
+            };
+            issues.push((i, issue));
+        }
+
+        let available = cards.len() - i - 1;
+        if card.matches() > available {
+            let issue = Issue::WinsPastEnd {
+                card: card.id,
+                wins: card.matches(),
+                available,
+            };
+            issues.push((i, issue));
+        }
+    }
+    issues
+}
+
+/// Checks cards sorted by id according to `policy`, returning the issues to
+/// report. `spans` are the spans of each card from [`parse_cards`](crate::parse_cards).
+pub fn validate(cards: &[Card], spans: &[CardSpans], policy: Policy) -> Result<Vec<Issue>> {
+    if policy == Policy::Ignore {
+        return Ok(Vec::new());
+    }
+    let mut issues = issues(cards).into_iter();
+    match policy {
+        Policy::Error => match issues.next() {
+            Some((i, issue)) => {
+                let spans = &spans[i];
+                Err(spans.line.error(issue.span(spans), issue.to_string()))
+            }
+            None => Ok(Vec::new()),
+        },
+        _ => Ok(issues.map(|(_, issue)| issue).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_cards, Day04, EXAMPLE};
+    use aoc_common::Solution;
+
+    fn check(input: &str, policy: Policy) -> Result<Vec<Issue>> {
+        let (cards, spans): (Vec<_>, Vec<_>) = parse_cards(input)?.into_iter().unzip();
+        validate(&cards, &spans, policy)
+    }
+
+    #[test]
+    fn test_issues() {
+        let input = "Card 1: 41 48 41 | 83 48  6
+Card 4:  1 21 53 | 1 21 14
+Card 2: 13 32 20 | 61 30 61";
+        let expected = vec![
+            (
+                0,
+                Issue::DuplicateNumber {
+                    card: 1,
+                    side: Side::Winning,
+                    number: 41,
+                    index: 2,
+                },
+            ),
+            (
+                1,
+                Issue::DuplicateNumber {
+                    card: 2,
+                    side: Side::Mine,
+                    number: 61,
+                    index: 2,
+                },
+            ),
+            (
+                2,
+                Issue::IdGap {
+                    expected: 3,
+                    found: 4,
+                },
+            ),
+            (
+                2,
+                Issue::WinsPastEnd {
+                    card: 4,
+                    wins: 2,
+                    available: 0,
+                },
+            ),
+        ];
+        let cards = Day04::parse(input).unwrap();
+        assert_eq!(issues(&cards), expected);
+    }
+
+    #[test]
+    fn test_validate_policy() {
+        let input = "Card 3: 4 | 5\nCard 2: 1 2 | 1 3";
+        assert_eq!(check(input, Policy::Clamp).unwrap().len(), 1);
+        assert!(check(input, Policy::Ignore).unwrap().is_empty());
+        let result = check(input, Policy::Error).unwrap_err();
+        assert_eq!(result.message, "card 2: expected card 1");
+        let location = result.location.unwrap();
+        assert_eq!((location.line, location.column, location.width), (2, 6, 1));
+    }
+
+    #[test]
+    fn test_error_locations() {
+        let cases = vec![
+            ("Card 1: 5 6 5 | 1", (1, 13, 1)),
+            ("Card 1: 5 | 7 17 17", (1, 18, 2)),
+            ("Card 1: 1 2 | 2 1\nCard 2: 3 | 4", (1, 1, 17)),
+        ];
+        for (input, expected) in cases {
+            let location = check(input, Policy::Error).unwrap_err().location.unwrap();
+            let result = (location.line, location.column, location.width);
+            assert_eq!(result, expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_warnings() {
+        let cards = Day04::parse("Card 1: 5 5 | 1\nCard 2: 1 | 2").unwrap();
+        let expected = vec!["card 1: duplicate winning number 5"];
+        assert_eq!(Day04::warnings(&cards), expected);
+        assert!(Day04::warnings(&Day04::parse(EXAMPLE).unwrap()).is_empty());
+    }
+}
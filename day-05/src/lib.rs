@@ -0,0 +1,280 @@
+use aoc_common::{
+    parse::{lines, Line},
+    range::{parse_range, Range},
+    ParseError, Result, Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    pub from: String,
+    pub to: String,
+    pub ranges: Vec<Range>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    /// Maps keyed by the category they convert from.
+    pub maps: HashMap<String, Map>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_almanac(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac> {
+    let mut lines = lines(input);
+    let (seeds_line, seeds) = match lines.next() {
+        Some(line) => (line, parse_seeds(&line)?),
+        None => return Err(ParseError::new("input is empty")),
+    };
+    let _ = lines.next();
+
+    let mut buff = Vec::new();
+    let mut almanac = HashMap::<String, Map>::new();
+    let mut headers = HashMap::<String, Line>::new();
+    for line in lines {
+        match line.text {
+            "" => {
+                let map = parse_map(&buff)?;
+                headers.insert(map.from.clone(), buff[0]);
+                almanac.insert(map.from.clone(), map);
+                buff.clear();
+            }
+            _ => buff.push(line),
+        }
+    }
+    if !buff.is_empty() {
+        let map = parse_map(&buff)?;
+        headers.insert(map.from.clone(), buff[0]);
+        almanac.insert(map.from.clone(), map);
+    }
+    check_chain(&seeds_line, &almanac, &headers)?;
+
+    Ok(Almanac {
+        seeds,
+        maps: almanac,
+    })
+}
+
+/// Checks the maps lead from `seed` to `location`, with no missing category
+/// and no loop, reporting the line which names the broken link.
+fn check_chain(
+    seeds: &Line,
+    maps: &HashMap<String, Map>,
+    headers: &HashMap<String, Line>,
+) -> Result<()> {
+    let mut category = "seed";
+    let mut line = seeds;
+    let mut seen = HashSet::new();
+    while category != "location" {
+        if !seen.insert(category) {
+            return Err(line.error(line.text, format!("maps loop back to `{category}`")));
+        }
+        let map = maps
+            .get(category)
+            .ok_or_else(|| line.error(line.text, format!("no map from `{category}`")))?;
+        line = &headers[category];
+        category = &map.to;
+    }
+    Ok(())
+}
+
+fn parse_seeds(line: &Line) -> Result<Vec<i64>> {
+    let seeds = line
+        .text
+        .strip_prefix("seeds:")
+        .ok_or_else(|| line.error(line.text, "expected `seeds:` prefix"))?;
+    let numbers = line.numbers(seeds)?;
+    // part 2 reads the seeds as `START LENGTH` pairs
+    if numbers.is_empty() || numbers.len() % 2 != 0 {
+        return Err(line.error(seeds, "expected `START LENGTH` pairs of seeds"));
+    }
+    Ok(numbers)
+}
+
+fn parse_map(lines: &[Line]) -> Result<Map> {
+    let (header, ranges) = lines
+        .split_first()
+        .ok_or_else(|| ParseError::new("expected a map"))?;
+    let (from, to) = header
+        .text
+        .strip_suffix(" map:")
+        .and_then(|h| h.split_once("-to-"))
+        .ok_or_else(|| header.error(header.text, "expected `FROM-to-TO map:`"))?;
+
+    let ranges = ranges
+        .iter()
+        .map(parse_range)
+        .collect::<Result<Vec<Range>>>()?;
+    if ranges.is_empty() {
+        return Err(header.error(header.end(), "expected at least one range"));
+    }
+
+    Ok(Map {
+        from: from.to_string(),
+        to: to.to_string(),
+        ranges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seeds() {
+        let input = Line {
+            number: 1,
+            text: "seeds: 79 14 55 13",
+        };
+        let expected = vec![79, 14, 55, 13];
+        let result = parse_seeds(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let input = "seed-to-soil map:
+50 98 2
+57 7 4";
+        let expected = Map {
+            from: "seed".to_string(),
+            to: "soil".to_string(),
+            ranges: vec![
+                Range {
+                    start: 98,
+                    end: 99,
+                    shift: -48,
+                },
+                Range {
+                    start: 7,
+                    end: 10,
+                    shift: 50,
+                },
+            ],
+        };
+        let result = parse_map(&lines(input).collect::<Vec<_>>()).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+
+soil-to-location map:
+37 52 2";
+        let expected_seeds = vec![79, 14, 55, 13];
+        let expected_maps = HashMap::from_iter([
+            (
+                "seed".to_string(),
+                Map {
+                    from: "seed".to_string(),
+                    to: "soil".to_string(),
+                    ranges: vec![Range {
+                        start: 98,
+                        end: 99,
+                        shift: -48,
+                    }],
+                },
+            ),
+            (
+                "soil".to_string(),
+                Map {
+                    from: "soil".to_string(),
+                    to: "location".to_string(),
+                    ranges: vec![Range {
+                        start: 52,
+                        end: 53,
+                        shift: -15,
+                    }],
+                },
+            ),
+        ]);
+        let result = parse_almanac(input).unwrap();
+        assert_eq!(result.seeds, expected_seeds);
+        assert_eq!(result.maps, expected_maps);
+    }
+
+    #[test]
+    fn test_parse_almanac_error() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+
+soil-to-fertilizer map:
+37 52";
+        let error = parse_almanac(input).unwrap_err();
+        let location = error.location.unwrap();
+        assert_eq!(error.message, "could not find length");
+        assert_eq!(location.line, 7);
+        assert_eq!(location.column, 6);
+    }
+
+    #[test]
+    fn test_parse_almanac_chain() {
+        let cases = vec![
+            (
+                "seeds: 79 14\n\nsoil-to-location map:\n1 2 3",
+                1,
+                "no map from `seed`",
+            ),
+            (
+                "seeds: 79 14\n\nseed-to-soil map:\n1 2 3",
+                3,
+                "no map from `soil`",
+            ),
+            (
+                "seeds: 79 14\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3",
+                6,
+                "maps loop back to `seed`",
+            ),
+            (
+                "seeds: 79 14\n\nseed-to-location map:",
+                3,
+                "expected at least one range",
+            ),
+            (
+                "seeds: 79\n\nseed-to-location map:\n1 2 3",
+                1,
+                "expected `START LENGTH` pairs of seeds",
+            ),
+            (
+                "seeds:\n\nseed-to-location map:\n1 2 3",
+                1,
+                "expected `START LENGTH` pairs of seeds",
+            ),
+        ];
+        for (input, line, message) in cases {
+            let error = parse_almanac(input).unwrap_err();
+            assert_eq!(error.location.unwrap().line, line, "{input}");
+            assert_eq!(error.message, message);
+        }
+    }
+}
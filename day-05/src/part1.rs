@@ -0,0 +1,74 @@
+use crate::{Almanac, Map};
+use std::collections::HashMap;
+
+pub fn part1(almanac: &Almanac) -> i64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|s| convert("seed".to_string(), *s, &almanac.maps))
+        .min()
+        .expect("Could not find solution")
+}
+
+fn convert(from: String, id: i64, alamac: &HashMap<String, Map>) -> i64 {
+    let map = alamac
+        .get(&from)
+        .expect("Parse should check the maps reach `location`");
+    let range = map.ranges.iter().find(|r| (r.start <= id) && (id <= r.end));
+    let new_id = match range {
+        Some(r) => id + r.shift,
+        None => id,
+    };
+
+    match map.to.as_str() {
+        "location" => new_id,
+        new_category => convert(new_category.to_string(), new_id, alamac),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let expected = 35;
+        let result = part1(&Day05::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
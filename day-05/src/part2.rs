@@ -0,0 +1,162 @@
+use crate::{Almanac, Map};
+use aoc_common::range::Range;
+use std::{cmp::Ordering, collections::HashMap};
+
+pub fn part2(almanac: &Almanac) -> i64 {
+    let transformed = seed_ranges(&almanac.seeds)
+        .into_iter()
+        .map(|s| convert("seed".to_string(), s, &almanac.maps));
+
+    transformed
+        .flatten()
+        .map(|r| r.start)
+        .min()
+        .expect("Couldn't find min location")
+}
+
+fn convert(from: String, range: Range, alamac: &HashMap<String, Map>) -> Vec<Range> {
+    if &from == "location" {
+        return vec![range];
+    }
+    let map = alamac
+        .get(&from)
+        .expect("Parse should check the maps reach `location`");
+    let mut transformations = map.ranges.iter().collect::<Vec<&Range>>();
+    transformations.sort_by_key(|t| t.start);
+    let mut padding = transformations
+        .windows(2)
+        .filter_map(|t| {
+            if t[0].end > t[1].start {
+                Some(Range {
+                    start: t[0].end + 1,
+                    end: t[1].start - 1,
+                    shift: 0,
+                })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<Range>>();
+    let first = transformations.first().unwrap();
+    if first.start != 0 {
+        padding.push(Range {
+            start: 0,
+            end: first.start - 1,
+            shift: 0,
+        });
+    }
+    padding.push(Range {
+        start: transformations.last().unwrap().end + 1,
+        end: i64::MAX,
+        shift: 0,
+    });
+    let mut padding_ref = padding.iter().collect::<Vec<&Range>>();
+    transformations.append(&mut padding_ref);
+
+    transformations
+        .iter()
+        .map(
+            |t| match (t.start.cmp(&range.start), t.end.cmp(&range.end)) {
+                (Ordering::Less | Ordering::Equal, Ordering::Greater | Ordering::Equal) => Range {
+                    start: range.start + t.shift,
+                    end: range.end + t.shift,
+                    shift: t.shift,
+                },
+                (Ordering::Greater, Ordering::Less) => Range {
+                    start: t.start + t.shift,
+                    end: t.end + t.shift,
+                    shift: t.shift,
+                },
+                (Ordering::Less | Ordering::Equal, Ordering::Less) => Range {
+                    start: range.start + t.shift,
+                    end: t.end + t.shift,
+                    shift: t.shift,
+                },
+                (Ordering::Greater, Ordering::Greater | Ordering::Equal) => Range {
+                    start: t.start + t.shift,
+                    end: range.end + t.shift,
+                    shift: t.shift,
+                },
+            },
+        )
+        .filter(|r| r.start <= r.end)
+        .flat_map(|r| convert(map.to.clone(), r, alamac))
+        .collect::<Vec<Range>>()
+}
+
+fn seed_ranges(seeds: &[i64]) -> Vec<Range> {
+    seeds
+        .chunks(2)
+        .map(|pair| Range {
+            start: pair[0],
+            end: pair[0] + pair[1] - 1,
+            shift: 0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_seed_ranges() {
+        let input = vec![79, 14, 55, 13];
+        let expected = vec![
+            Range {
+                start: 79,
+                end: 92,
+                shift: 0,
+            },
+            Range {
+                start: 55,
+                end: 67,
+                shift: 0,
+            },
+        ];
+        let result = seed_ranges(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let expected = 46;
+        let result = part2(&Day05::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
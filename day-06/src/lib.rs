@@ -0,0 +1,122 @@
+use aoc_common::{
+    parse::{lines, Line},
+    ParseError, Result, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+use part2::join_digits;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Races {
+    pub time: Vec<i64>,
+    pub distance: Vec<i64>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = lines(input);
+        let time = parse_line(&lines.next().ok_or(ParseError::new("time not found"))?)?;
+        let line = lines.next().ok_or(ParseError::new("distance not found"))?;
+        let distance = parse_line(&line)?;
+        if distance.len() != time.len() {
+            let message = format!("expected {} distances, one per time", time.len());
+            return Err(line.error(line.strip_label()?, message));
+        }
+        Ok(Races { time, distance })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn parse_line(line: &Line) -> Result<Vec<i64>> {
+    let body = line.strip_label()?;
+    let numbers = line.numbers(body)?;
+    if numbers.is_empty() {
+        return Err(line.error(line.end(), "expected at least one number"));
+    }
+    // part 2 reads the whole line as a single number
+    if join_digits(&numbers).is_none() {
+        return Err(line.error(body, "numbers are too large to read as one"));
+    }
+    Ok(numbers)
+}
+
+/// Number of whole button hold times which beat the record `distance`.
+fn ways_to_win(time: i64, distance: i64) -> i64 {
+    let (b1, b2) = quadratic_root(1, -time, distance);
+    (b2.ceil() as i64) - 1 - (b1.floor() as i64)
+}
+
+fn quadratic_root(a: i64, b: i64, c: i64) -> (f64, f64) {
+    let d = (b.pow(2) - 4 * a * c) as f64;
+    let a = a as f64;
+    let b = b as f64;
+    ((-b - d.sqrt()) / (2.0 * a), (-b + d.sqrt()) / (2.0 * a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let input = Line {
+            number: 1,
+            text: "Time:      7  15   30",
+        };
+        let expected = vec![7, 15, 30];
+        let result = parse_line(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Time:      7  15   30";
+        let error = Day06::parse(input).unwrap_err();
+        assert_eq!(error.message, "distance not found");
+
+        let cases = vec![
+            ("Time:\nDistance:", 1, "expected at least one number"),
+            (
+                "Time: 7 15\nDistance: 9",
+                2,
+                "expected 2 distances, one per time",
+            ),
+            (
+                "Time: 7\nDistance: 9999999999 9999999999",
+                2,
+                "numbers are too large to read as one",
+            ),
+        ];
+        for (input, line, message) in cases {
+            let error = Day06::parse(input).unwrap_err();
+            assert_eq!(error.location.unwrap().line, line, "{input}");
+            assert_eq!(error.message, message);
+        }
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        let cases = vec![(7, 9, 4), (15, 40, 8), (30, 200, 9)];
+        for (time, distance, expected) in cases {
+            let result = ways_to_win(time, distance);
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{ways_to_win, Races};
+use std::iter::zip;
+
+pub fn part1(races: &Races) -> i64 {
+    zip(&races.time, &races.distance)
+        .map(|(t, d)| ways_to_win(*t, *d))
+        .product::<i64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let expected = 288;
+        let result = part1(&Day06::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{ways_to_win, Races};
+
+pub fn part2(races: &Races) -> i64 {
+    let join = |numbers| join_digits(numbers).expect("Parse should check the numbers join");
+    ways_to_win(join(&races.time), join(&races.distance))
+}
+
+/// Reads the numbers as a single number, ignoring the spaces between them,
+/// `None` when that number does not fit.
+pub(crate) fn join_digits(numbers: &[i64]) -> Option<i64> {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_join_digits() {
+        let input = vec![7, 15, 30];
+        let expected = Some(71530);
+        let result = join_digits(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let expected = 71503;
+        let result = part2(&Day06::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
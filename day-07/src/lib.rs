@@ -0,0 +1,100 @@
+use aoc_common::{
+    parse::{lines, Line},
+    Result, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Type {
+    FiveOfKind,
+    FourOfKind,
+    FullHouse,
+    ThreeOfKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Play {
+    pub cards: String,
+    pub bid: u32,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Play>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input).map(|line| parse_line(&line)).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn parse_line(line: &Line) -> Result<Play> {
+    let mut parts = line.text.split_whitespace();
+    let cards = parts
+        .next()
+        .ok_or_else(|| line.error(line.end(), "could not find hand"))?;
+    if let Some((i, _)) = cards
+        .char_indices()
+        .find(|(_, c)| !"AKQJT98765432".contains(*c))
+    {
+        return Err(line.error(&cards[i..i + 1], "invalid card"));
+    }
+    let bid = parts
+        .next()
+        .ok_or_else(|| line.error(line.end(), "could not find bid"))?;
+    Ok(Play {
+        cards: cards.to_string(),
+        bid: line.parse::<u32>(bid, "bid")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let input = Line {
+            number: 1,
+            text: "32T3K 765",
+        };
+        let expected = Play {
+            cards: "32T3K".to_string(),
+            bid: 765,
+        };
+        let result = parse_line(&input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_line_error() {
+        let cases = vec![
+            ("32T3K x65", 7, "could not parse bid `x65`"),
+            ("32X3K 765", 3, "invalid card"),
+            ("32T3K", 6, "could not find bid"),
+        ];
+        for (text, column, message) in cases {
+            let input = Line { number: 1, text };
+            let error = parse_line(&input).unwrap_err();
+            assert_eq!(error.message, message);
+            assert_eq!(error.location.unwrap().column, column);
+        }
+    }
+}
@@ -1,16 +1,6 @@
+use crate::{Play, Type};
 use std::{cmp::Ordering, collections::HashMap, iter::zip};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Type {
-    FiveOfKind,
-    FourOfKind,
-    FullHouse,
-    ThreeOfKind,
-    TwoPair,
-    OnePair,
-    HighCard,
-}
-
 #[derive(Debug)]
 struct Hand {
     cards: String,
@@ -63,14 +53,8 @@ impl PartialOrd for Hand {
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string("./input.txt").unwrap();
-    let output = part1(input);
-    dbg!(output);
-}
-
-fn part1(input: String) -> u32 {
-    let mut hands = input.lines().map(parse_line).collect::<Vec<_>>();
+pub fn part1(plays: &[Play]) -> u32 {
+    let mut hands = plays.iter().map(to_hand).collect::<Vec<_>>();
     hands.sort();
     hands
         .into_iter()
@@ -80,18 +64,11 @@ fn part1(input: String) -> u32 {
         .sum()
 }
 
-fn parse_line(line: &str) -> Hand {
-    let mut parts = line.split_whitespace();
-    let cards = parts.next().expect("Could not parse hand");
-    let bid = parts
-        .next()
-        .expect("Could not parse bid")
-        .parse::<u32>()
-        .expect("Could not parse bid");
+fn to_hand(play: &Play) -> Hand {
     Hand {
-        cards: cards.to_string(),
-        hand_type: parse_type(cards),
-        bid,
+        cards: play.cards.clone(),
+        hand_type: parse_type(&play.cards),
+        bid: play.bid,
     }
 }
 
@@ -107,7 +84,7 @@ fn parse_type(cards: &str) -> Type {
     let mut count = count.values().collect::<Vec<&u32>>();
     count.sort();
     count.reverse();
-    let first = count.get(0);
+    let first = count.first();
     let second = count.get(1);
 
     match (first, second) {
@@ -124,16 +101,18 @@ fn parse_type(cards: &str) -> Type {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
 
     #[test]
-    fn test_parse_line() {
+    fn test_to_hand() {
         let input = "32T3K 765";
         let expected = Hand {
             cards: "32T3K".to_string(),
             hand_type: Type::OnePair,
             bid: 765,
         };
-        let result = parse_line(input);
+        let result = to_hand(&Day07::parse(input).unwrap()[0]);
         assert_eq!(result, expected);
     }
 
@@ -203,10 +182,9 @@ mod tests {
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483"
-            .to_string();
+QQQJA 483";
         let expected = 6440;
-        let result = part1(input);
+        let result = part1(&Day07::parse(input).unwrap());
         assert_eq!(result, expected);
     }
 }
@@ -0,0 +1,124 @@
+use aoc_common::{
+    node::{parse_node, Node},
+    parse::lines,
+    ParseError, Result, Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Network {
+    pub instructions: Vec<char>,
+    /// Nodes keyed by their label.
+    pub nodes: HashMap<String, Node>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = lines(input);
+        let line = lines.next().ok_or(ParseError::new("input is empty"))?;
+        if let Some((i, _)) = line.text.char_indices().find(|(_, c)| !"LR".contains(*c)) {
+            return Err(line.error(&line.text[i..i + 1], "expected `L` or `R` instruction"));
+        }
+        if line.text.is_empty() {
+            return Err(line.error(line.end(), "expected at least one instruction"));
+        }
+        let instructions = line.text.chars().collect();
+
+        let nodes = lines
+            .skip(1)
+            .map(|line| parse_node(&line).map(|node| (line, node)))
+            .collect::<Result<Vec<_>>>()?;
+        let labels = nodes
+            .iter()
+            .map(|(_, node)| node.label.as_str())
+            .collect::<HashSet<_>>();
+        for (line, node) in &nodes {
+            for child in [&node.left, &node.right] {
+                if !labels.contains(child.as_str()) {
+                    let span = match line.text.rfind(child.as_str()) {
+                        Some(i) => &line.text[i..i + child.len()],
+                        None => line.text,
+                    };
+                    return Err(line.error(span, format!("no node labelled `{child}`")));
+                }
+            }
+        }
+        let nodes = nodes
+            .into_iter()
+            .map(|(_, node)| (node.label.clone(), node))
+            .collect();
+
+        Ok(Network {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+
+    fn warnings(input: &Self::Input) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !input.nodes.contains_key("AAA") {
+            warnings.push("no `AAA` node, part 1 counts 0 steps".to_string());
+        }
+        if !input.nodes.keys().any(|label| label.ends_with('A')) {
+            warnings.push("no node ending in `A`, part 2 counts 0 steps".to_string());
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let cases = vec![
+            ("LXR\n\nAAA = (BBB, BBB)", 1, 2),
+            ("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA ZZZ)", 4, 7),
+            ("\n\nAAA = (AAA, AAA)", 1, 1),
+            (
+                "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, CCC)",
+                5,
+                13,
+            ),
+        ];
+        for (input, line, column) in cases {
+            let location = Day08::parse(input).unwrap_err().location.unwrap();
+            assert_eq!((location.line, location.column), (line, column));
+        }
+    }
+
+    #[test]
+    fn test_warnings() {
+        let network = Day08::parse("LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert!(Day08::warnings(&network).is_empty());
+
+        let network = Day08::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let expected = vec![
+            "no `AAA` node, part 1 counts 0 steps",
+            "no node ending in `A`, part 2 counts 0 steps",
+        ];
+        assert_eq!(Day08::warnings(&network), expected);
+        assert_eq!(Day08::part1(&network), 0);
+        assert_eq!(Day08::part2(&network), 0);
+    }
+}
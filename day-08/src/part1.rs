@@ -0,0 +1,43 @@
+use crate::Network;
+
+pub fn part1(network: &Network) -> u32 {
+    let nodes = &network.nodes;
+    let mut hops = 0;
+    let Some(mut current_node) = nodes.get("AAA") else {
+        return 0;
+    };
+    for inst in network.instructions.iter().cycle() {
+        hops += 1;
+        let next_node_label = match inst {
+            'L' => current_node.left.as_str(),
+            _ => current_node.right.as_str(),
+        };
+        if next_node_label == "ZZZ" {
+            break;
+        }
+        current_node = nodes
+            .get(next_node_label)
+            .expect("Parse should check every child is a node");
+    }
+
+    hops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_common::Solution;
+
+    #[test]
+    fn it_works() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let expected = 6;
+        let result = part1(&Day08::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
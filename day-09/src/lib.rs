@@ -0,0 +1,52 @@
+use aoc_common::{parse::lines, Result, Solution};
+use itertools::Itertools;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input).map(|line| line.numbers(line.text)).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::part2(input)
+    }
+}
+
+fn diff(seq: &[i32]) -> Vec<i32> {
+    seq.windows(2).map(|s| s[1] - s[0]).collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "0 3 6 9 12 15
+1 3 6 1O 15 21";
+        let location = Day09::parse(input).unwrap_err().location.unwrap();
+        assert_eq!((location.line, location.column), (2, 7));
+    }
+
+    #[test]
+    fn test_diff() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        let expected = vec![3, 3, 3, 3, 3];
+        let result = diff(&input);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::diff;
+
+pub fn part2(sequences: &[Vec<i32>]) -> i32 {
+    sequences.iter().map(|seq| extrapolate(seq)).sum()
+}
+
+fn extrapolate(seq: &[i32]) -> i32 {
+    let mut buff = vec![*seq.first().unwrap()];
+    let mut last_diff = seq.to_vec();
+    loop {
+        last_diff = diff(&last_diff);
+        buff.push(*last_diff.first().unwrap());
+        if last_diff.iter().all(|x| *x == 0) {
+            break;
+        }
+    }
+    buff.into_iter().rev().reduce(|ans, x| x - ans).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_extrapolate() {
+        let input = vec![10, 13, 16, 21, 30, 45];
+        let expected = 5;
+        let result = extrapolate(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let expected = 2;
+        let result = part2(&Day09::parse(input).unwrap());
+        assert_eq!(result, expected);
+    }
+}
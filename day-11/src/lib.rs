@@ -0,0 +1,43 @@
+use aoc_common::{Grid, Result, Solution, Unsolved};
+
+pub mod part1;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    /// `true` where there is a galaxy.
+    type Input = Grid<bool>;
+    type Part1 = u64;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err("expected `.` or `#`".to_string()),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "...#..
+.#..x.";
+        let location = Day11::parse(input).unwrap_err().location.unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+    }
+}
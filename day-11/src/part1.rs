@@ -0,0 +1,94 @@
+use aoc_common::{geometry::Point, Grid};
+use itertools::Itertools;
+
+pub fn part1(image: &Grid<bool>) -> u64 {
+    let galaxies = expand_universe(image, 2);
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(g1, g2)| g1.manhattan(*g2))
+        .sum()
+}
+
+/// Galaxy positions once every empty row and column is `factor` times as large.
+fn expand_universe(image: &Grid<bool>, factor: i64) -> Vec<Point> {
+    let empty_rows = image.rows().map(|row| !row.contains(&true)).collect_vec();
+    let empty_cols = image
+        .columns()
+        .map(|mut col| !col.contains(&true))
+        .collect_vec();
+    let expand = |empty: &[bool], i: i64| {
+        i + empty[..i as usize].iter().filter(|e| **e).count() as i64 * (factor - 1)
+    };
+    parse_galaxies(image)
+        .into_iter()
+        .map(|p| Point::new(expand(&empty_cols, p.x), expand(&empty_rows, p.y)))
+        .collect_vec()
+}
+
+fn parse_galaxies(image: &Grid<bool>) -> Vec<Point> {
+    image
+        .iter()
+        .filter(|(_, galaxy)| **galaxy)
+        .map(|(p, _)| p)
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use aoc_common::Solution;
+
+    const IMAGE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_parse_galaxies() {
+        let expected = vec![
+            Point::new(3, 0),
+            Point::new(7, 1),
+            Point::new(0, 2),
+            Point::new(6, 4),
+            Point::new(1, 5),
+            Point::new(9, 6),
+            Point::new(7, 8),
+            Point::new(0, 9),
+            Point::new(4, 9),
+        ];
+        let result = parse_galaxies(&Day11::parse(IMAGE).unwrap());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_expand_universe() {
+        let expected = vec![
+            Point::new(4, 0),
+            Point::new(9, 1),
+            Point::new(0, 2),
+            Point::new(8, 5),
+            Point::new(1, 6),
+            Point::new(12, 7),
+            Point::new(9, 10),
+            Point::new(0, 11),
+            Point::new(5, 11),
+        ];
+        let result = expand_universe(&Day11::parse(IMAGE).unwrap(), 2);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn it_works() {
+        let expected = 374;
+        let result = part1(&Day11::parse(IMAGE).unwrap());
+        assert_eq!(result, expected);
+    }
+}